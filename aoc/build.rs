@@ -0,0 +1,91 @@
+//! Marks the days whose full input is missing, or empty, with the `missing_input` cfg, e.g.,
+//! `missing_input = "2023_5"`, so their tests are reported as ignored instead of passing.
+//! Nothing is marked if `AOC_STRICT_INPUTS` is set, so the tests fail instead.
+
+use std::path::{Path, PathBuf};
+
+const INPUTS_ROOT_ENV: &str = "INPUTS_ROOT";
+const DEFAULT_INPUTS_ROOT: &str = "inputs";
+const STRICT_INPUTS_ENV: &str = "AOC_STRICT_INPUTS";
+
+fn main() {
+    println!("cargo::rustc-check-cfg=cfg(missing_input, values(any()))");
+    println!("cargo::rerun-if-changed=build.rs");
+    println!("cargo::rerun-if-changed=src");
+    println!("cargo::rerun-if-env-changed={}", INPUTS_ROOT_ENV);
+    println!("cargo::rerun-if-env-changed={}", STRICT_INPUTS_ENV);
+
+    let root = match std::env::var(INPUTS_ROOT_ENV) {
+        Ok(root) => PathBuf::from(root),
+        Err(_) => PathBuf::from(DEFAULT_INPUTS_ROOT),
+    };
+
+    println!("cargo::rerun-if-changed={}", root.display());
+
+    let is_strict =
+        std::env::var(STRICT_INPUTS_ENV).is_ok_and(|value| !matches!(value.as_str(), "" | "0"));
+
+    if is_strict {
+        return;
+    }
+
+    for (year, day) in days(Path::new("src")) {
+        if !has_input(&root, year, day) {
+            println!("cargo::rustc-cfg=missing_input=\"{}_{}\"", year, day);
+        }
+    }
+}
+
+/// Year and day of each `src/year_YYYY/day_N.rs` module.
+fn days(src: &Path) -> Vec<(u16, u8)> {
+    let mut days = Vec::new();
+
+    for year_dir in read_dir(src) {
+        let year = file_stem(&year_dir)
+            .strip_prefix("year_")
+            .and_then(|year| year.parse::<u16>().ok());
+
+        if let Some(year) = year.filter(|_| year_dir.is_dir()) {
+            for module in read_dir(&year_dir) {
+                let day = file_stem(&module)
+                    .strip_prefix("day_")
+                    .and_then(|day| day.parse::<u8>().ok());
+
+                if let Some(day) = day {
+                    days.push((year, day));
+                }
+            }
+        }
+    }
+
+    days
+}
+
+/// Same lookup as `input::find_input`, but an empty file counts as missing.
+fn has_input(root: &Path, year: u16, day: u8) -> bool {
+    let file_name = format!("day_{}.txt", day);
+
+    [
+        root.join(year.to_string()).join(&file_name),
+        root.join(file_name),
+    ]
+    .iter()
+    .find(|path| path.is_file())
+    .is_some_and(|path| path.metadata().is_ok_and(|meta| meta.len() > 0))
+}
+
+fn read_dir(dir: &Path) -> Vec<PathBuf> {
+    match std::fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
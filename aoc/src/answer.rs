@@ -0,0 +1,147 @@
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+/// Answer to a puzzle part, either an integer or text.
+///
+/// Integers that fit an i128 are held as [`Answer::Integer`], wider ones as the decimal
+/// digits of [`Answer::BigInteger`], so each integer has exactly one representation.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Integer(i128),
+    /// Integer beyond i128, as decimal digits with an optional `-` and no leading zeros.
+    BigInteger(String),
+    Text(String),
+}
+
+impl Answer {
+    /// Integer of any width given by its decimal digits, with an optional sign, if the text is one.
+    pub fn integer(digits: &str) -> Option<Answer> {
+        if let Ok(value) = digits.parse::<i128>() {
+            return Some(Answer::Integer(value));
+        }
+
+        let (sign, magnitude) = match digits.strip_prefix('-') {
+            Some(magnitude) => ("-", magnitude),
+            None => ("", digits.strip_prefix('+').unwrap_or(digits)),
+        };
+
+        if magnitude.is_empty() || !magnitude.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        // Out of range of i128 with at least one non-zero digit, so neither zero nor "-0".
+        Some(Answer::BigInteger(format!(
+            "{}{}",
+            sign,
+            magnitude.trim_start_matches('0')
+        )))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(digits) => write!(f, "{}", digits),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Error;
+
+    /// Integer of any width if the text is one, else text.
+    fn from_str(s: &str) -> Result<Answer, Error> {
+        Ok(Answer::integer(s).unwrap_or_else(|| Answer::Text(s.to_string())))
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Answer {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, i64, i128, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Answer {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Answer {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Answer {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn holds_wide_integers_and_text() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from(i64::MIN).to_string(), "-9223372036854775808");
+        assert_eq!(Answer::from("LRRL").to_string(), "LRRL");
+        assert_eq!(
+            Answer::from(u128::MAX),
+            Answer::BigInteger("340282366920938463463374607431768211455".to_string())
+        );
+        assert_eq!(Answer::from(42_u128), Answer::Integer(42));
+    }
+
+    #[test]
+    fn parse_integer_or_text() {
+        assert_eq!("-42".parse::<Answer>().unwrap(), Answer::Integer(-42));
+        assert_eq!(
+            "18446744073709551615".parse::<Answer>().unwrap(),
+            Answer::from(u64::MAX)
+        );
+        assert_eq!("LRRL".parse::<Answer>().unwrap(), Answer::from("LRRL"));
+        assert_eq!("-".parse::<Answer>().unwrap(), Answer::from("-"));
+    }
+
+    #[test]
+    fn parse_integers_beyond_i128() {
+        assert_eq!(
+            "340282366920938463463374607431768211455"
+                .parse::<Answer>()
+                .unwrap(),
+            Answer::from(u128::MAX)
+        );
+        assert_eq!(
+            "-000170141183460469231731687303715884105729"
+                .parse::<Answer>()
+                .unwrap()
+                .to_string(),
+            "-170141183460469231731687303715884105729"
+        );
+        assert_eq!(
+            "+0042".parse::<Answer>().unwrap(),
+            Answer::Integer(42),
+            "Narrow integers are never big"
+        );
+    }
+}
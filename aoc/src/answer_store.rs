@@ -0,0 +1,208 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use toml::Spanned;
+
+use crate::answer::Answer;
+use crate::config::{toml_error, toml_error_at};
+use crate::error::{Error, Result};
+use crate::types::Part;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Known-correct answers keyed by year, day and part, stored as TOML, e.g.:
+///
+/// ```toml
+/// [2023.day_1]
+/// part_1 = 53080
+/// ```
+#[derive(Default, Debug)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u16, u8, u8), Answer>,
+}
+
+fn part_number(part: Part) -> u8 {
+    match part {
+        Part::One => 1,
+        Part::Two => 2,
+    }
+}
+
+impl AnswerStore {
+    /// Loads `path`, or an empty store if it does not exist.
+    pub fn load(path: &std::path::Path) -> Result<AnswerStore> {
+        if !path.exists() {
+            return Ok(AnswerStore::default());
+        }
+
+        let file = std::fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+
+        AnswerStore::from_toml(&file)
+    }
+
+    pub fn save(&self, path: &std::path::Path) -> Result<()> {
+        std::fs::write(path, self.to_toml()).map_err(|e| Error::io(path, e))
+    }
+
+    pub fn from_toml(text: &str) -> Result<AnswerStore> {
+        type Parts = BTreeMap<Spanned<String>, Spanned<toml::Value>>;
+
+        let years: BTreeMap<String, BTreeMap<String, Parts>> =
+            toml::from_str(text).map_err(|e| toml_error(text, &e))?;
+
+        let mut store = AnswerStore::default();
+
+        for (year, days) in years {
+            for (day, parts) in days {
+                for (part, value) in parts {
+                    let invalid = || {
+                        toml_error_at(
+                            text,
+                            part.span().start,
+                            &format!("Invalid answer {}.{}.{}", year, day, part.get_ref()),
+                        )
+                    };
+
+                    let key = (
+                        year.parse::<u16>().ok(),
+                        day.strip_prefix("day_")
+                            .and_then(|day| day.parse::<u8>().ok()),
+                        part.get_ref()
+                            .strip_prefix("part_")
+                            .and_then(|part| part.parse::<u8>().ok()),
+                    );
+
+                    let answer = match value.into_inner() {
+                        toml::Value::Integer(value) => Answer::Integer(value as i128),
+                        toml::Value::String(value) => value.parse()?,
+                        _ => return Err(invalid()),
+                    };
+
+                    match key {
+                        (Some(year), Some(day), Some(part @ (1 | 2))) => {
+                            store.answers.insert((year, day, part), answer);
+                        }
+                        _ => return Err(invalid()),
+                    }
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        let mut table: Option<(u16, u8)> = None;
+
+        for ((year, day, part), answer) in &self.answers {
+            if table != Some((*year, *day)) {
+                if table.is_some() {
+                    toml.push('\n');
+                }
+
+                toml.push_str(&format!("[{}.day_{}]\n", year, day));
+                table = Some((*year, *day));
+            }
+
+            let value = match answer {
+                Answer::Integer(value) if i64::try_from(*value).is_ok() => value.to_string(),
+                _ => toml::Value::String(answer.to_string()).to_string(),
+            };
+
+            toml.push_str(&format!("part_{} = {}\n", part, value));
+        }
+
+        toml
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(year, day, part_number(part)))
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: Part, answer: Answer) {
+        self.answers.insert((year, day, part_number(part)), answer);
+    }
+
+    pub fn verify(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Verdict {
+        match self.get(year, day, part) {
+            Some(known) if known == answer => Verdict::Correct,
+            Some(_) => Verdict::Wrong,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnswerStore, Verdict};
+    use crate::answer::Answer;
+    use crate::error::Error;
+    use crate::types::Part;
+
+    #[test]
+    fn round_trip() {
+        let mut store = AnswerStore::default();
+        store.record(2023, 2, Part::One, Answer::from(2278));
+        store.record(2023, 1, Part::Two, Answer::from(u64::MAX));
+        store.record(2023, 1, Part::One, Answer::from("LRRL"));
+        store.record(2023, 2, Part::Two, Answer::from(u128::MAX));
+
+        let toml = store.to_toml();
+
+        assert_eq!(
+            toml,
+            "[2023.day_1]\npart_1 = \"LRRL\"\npart_2 = \"18446744073709551615\"\n\n\
+             [2023.day_2]\npart_1 = 2278\npart_2 = \"340282366920938463463374607431768211455\"\n"
+        );
+
+        let loaded = AnswerStore::from_toml(&toml).unwrap();
+
+        assert_eq!(
+            loaded.get(2023, 1, Part::Two),
+            Some(&Answer::from(u64::MAX))
+        );
+        assert_eq!(loaded.get(2023, 2, Part::One), Some(&Answer::from(2278)));
+        assert_eq!(
+            loaded.get(2023, 2, Part::Two),
+            Some(&Answer::from(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn verify_answers() {
+        let store = AnswerStore::from_toml("[2023.day_1]\npart_1 = 53080\n").unwrap();
+
+        let verify = |part, answer: i32| store.verify(2023, 1, part, &answer.into());
+
+        assert_eq!(verify(Part::One, 53080), Verdict::Correct);
+        assert_eq!(verify(Part::One, 1), Verdict::Wrong);
+        assert_eq!(verify(Part::Two, 53268), Verdict::Unknown);
+    }
+
+    #[test]
+    fn rejects_invalid_part() {
+        match AnswerStore::from_toml("[2023.day_1]\npart_1 = 1\npart_3 = 1\n") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (3, 1)),
+            _ => panic!("Expected parse error"),
+        }
+    }
+}
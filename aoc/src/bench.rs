@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::input::RawInput;
+use crate::solver::{AnySolver, Meta};
+use crate::types::Part;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Solve(part) => write!(f, "part_{}", part),
+        }
+    }
+}
+
+impl std::str::FromStr for Phase {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Phase::Parse),
+            "part_1" => Ok(Phase::Solve(Part::One)),
+            "part_2" => Ok(Phase::Solve(Part::Two)),
+            _ => Err(format!("Unsupported phase: {}", s)),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        if samples.is_empty() {
+            panic!("No samples to compute stats from");
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(|x| x.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|x| (x.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub phase: Phase,
+    pub stats: Stats,
+}
+
+/// Times the parse phase and each solve phase separately, over a number of iterations.
+/// The input is read into memory once beforehand, so the parse phase has no file I/O.
+pub fn bench(
+    solver: &dyn AnySolver,
+    input: &RawInput,
+    parts: &[Part],
+    iterations: usize,
+) -> Result<Vec<Measurement>> {
+    let Meta { year, day, .. } = solver.meta();
+    let input = &RawInput::from(input.read_to_string()?.as_str());
+    let mut measurements: Vec<Measurement> = Vec::new();
+
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(solver.parse_any(black_box(input))?);
+        samples.push(start.elapsed());
+    }
+
+    measurements.push(Measurement {
+        year,
+        day,
+        phase: Phase::Parse,
+        stats: Stats::from_samples(&samples),
+    });
+
+    let parsed = solver.parse_any(input)?;
+
+    for part in parts {
+        samples.clear();
+
+        for _ in 0..iterations {
+            let start = Instant::now();
+            black_box(solver.solve_any(black_box(parsed.as_ref()), *part)?);
+            samples.push(start.elapsed());
+        }
+
+        log::debug!("Benched {} day {} part {}", year, day, part);
+
+        measurements.push(Measurement {
+            year,
+            day,
+            phase: Phase::Solve(*part),
+            stats: Stats::from_samples(&samples),
+        });
+    }
+
+    Ok(measurements)
+}
+
+/// Median timings of an earlier run, stored as lines of `<year> <day> <phase> <median ns>`.
+#[derive(Default)]
+pub struct Baseline {
+    medians: HashMap<(u16, u8, Phase), Duration>,
+}
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Baseline {
+        Baseline {
+            medians: measurements
+                .iter()
+                .map(|m| ((m.year, m.day, m.phase), m.stats.median))
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &std::path::Path) -> Result<Baseline> {
+        let file = std::fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        let mut baseline = Baseline::default();
+
+        for (i_line, line) in file.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let malformed = || Error::parse(i_line, 0, line, "Malformed baseline line");
+
+            match fields[..] {
+                [year, day, phase, median] => {
+                    baseline.medians.insert(
+                        (
+                            year.parse().map_err(|_| malformed())?,
+                            day.parse().map_err(|_| malformed())?,
+                            phase.parse().map_err(|_| malformed())?,
+                        ),
+                        Duration::from_nanos(median.parse().map_err(|_| malformed())?),
+                    );
+                }
+                _ => return Err(malformed()),
+            }
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &std::path::Path) -> Result<()> {
+        let mut entries: Vec<(&(u16, u8, Phase), &Duration)> = self.medians.iter().collect();
+        entries.sort_by_key(|((year, day, phase), _)| (*year, *day, phase.to_string()));
+
+        let lines: Vec<String> = entries
+            .iter()
+            .map(|((year, day, phase), median)| {
+                format!("{} {} {} {}", year, day, phase, median.as_nanos())
+            })
+            .collect();
+
+        std::fs::write(path, lines.join("\n") + "\n").map_err(|e| Error::io(path, e))
+    }
+
+    /// Relative change of the median compared to the baseline, e.g., 0.1 for 10% slower.
+    pub fn change(&self, measurement: &Measurement) -> Option<f64> {
+        let baseline = self
+            .medians
+            .get(&(measurement.year, measurement.day, measurement.phase))?;
+
+        Some(measurement.stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Baseline, Measurement, Phase, Stats};
+    use crate::types::Part;
+    use crate::utils::test::temp_path;
+
+    #[test]
+    fn stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2]
+            .iter()
+            .map(|x| Duration::from_millis(*x))
+            .collect();
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let path = temp_path("baseline_round_trip.txt");
+        let measurement = Measurement {
+            year: 2023,
+            day: 4,
+            phase: Phase::Solve(Part::Two),
+            stats: Stats::from_samples(&[Duration::from_millis(10)]),
+        };
+
+        Baseline::from_measurements(&[measurement])
+            .save(&path)
+            .unwrap();
+
+        let slower = Measurement {
+            year: 2023,
+            day: 4,
+            phase: Phase::Solve(Part::Two),
+            stats: Stats::from_samples(&[Duration::from_millis(15)]),
+        };
+        let other_year = Measurement {
+            year: 2024,
+            day: 4,
+            phase: Phase::Solve(Part::Two),
+            stats: Stats::from_samples(&[Duration::from_millis(15)]),
+        };
+        let baseline = Baseline::load(&path).unwrap();
+
+        assert!((baseline.change(&slower).unwrap() - 0.5).abs() < 1e-9);
+        assert!(baseline.change(&other_year).is_none());
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
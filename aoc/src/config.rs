@@ -0,0 +1,148 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::answer_store::ANSWERS_FILE;
+use crate::error::{Error, Result};
+use crate::solver::Params;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+pub const INPUTS_ROOT_ENV: &str = "INPUTS_ROOT";
+pub const DEFAULT_INPUTS_ROOT: &str = "inputs";
+
+/// Optional settings read from an `aoc.toml` file, paths are relative to the file.
+#[derive(Deserialize, Default, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub inputs_root: Option<std::path::PathBuf>,
+    pub answers: Option<std::path::PathBuf>,
+    /// Parameters of the solvers by year and day, e.g., a `[params.2023.2]` table.
+    #[serde(default)]
+    pub params: BTreeMap<String, BTreeMap<String, Params>>,
+}
+
+impl Config {
+    pub fn load(path: &std::path::Path) -> Result<Config> {
+        let file = std::fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+
+        let mut config = Config::from_toml(&file)?;
+        let dir = path.parent().unwrap_or(std::path::Path::new(""));
+
+        config.inputs_root = config.inputs_root.map(|root| dir.join(root));
+        config.answers = config.answers.map(|answers| dir.join(answers));
+
+        Ok(config)
+    }
+
+    /// Loads `path` if given, else the default config file if present.
+    pub fn load_or_default(path: Option<&std::path::Path>) -> Result<Config> {
+        match path {
+            Some(path) => Config::load(path),
+            None if std::path::Path::new(CONFIG_FILE).is_file() => {
+                Config::load(std::path::Path::new(CONFIG_FILE))
+            }
+            None => Ok(Config::default()),
+        }
+    }
+
+    pub fn from_toml(text: &str) -> Result<Config> {
+        toml::from_str(text).map_err(|e| toml_error(text, &e))
+    }
+
+    /// Inputs root by precedence: flag, environment variable, config file and default.
+    pub fn inputs_root(&self, flag: Option<&std::path::Path>) -> std::path::PathBuf {
+        if let Some(root) = flag {
+            return root.to_path_buf();
+        }
+
+        if let Ok(root) = std::env::var(INPUTS_ROOT_ENV) {
+            return std::path::PathBuf::from(root);
+        }
+
+        match &self.inputs_root {
+            Some(root) => root.clone(),
+            None => std::path::PathBuf::from(DEFAULT_INPUTS_ROOT),
+        }
+    }
+
+    /// Answers file by precedence: flag, config file and default.
+    pub fn answers_path(&self, flag: Option<&std::path::Path>) -> std::path::PathBuf {
+        match (flag, &self.answers) {
+            (Some(path), _) => path.to_path_buf(),
+            (None, Some(path)) => path.clone(),
+            (None, None) => std::path::PathBuf::from(ANSWERS_FILE),
+        }
+    }
+
+    /// Parameters of a day, none unless set.
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        self.params
+            .get(&year.to_string())
+            .and_then(|days| days.get(&day.to_string()))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Parameters of a day, overridden by the given ones, e.g., of the command line.
+    pub fn params_with(&self, year: u16, day: u8, overrides: &Params) -> Params {
+        let mut params = self.params(year, day);
+        params.extend(overrides.clone());
+
+        params
+    }
+}
+
+pub fn toml_error(text: &str, error: &toml::de::Error) -> Error {
+    let offset = error.span().map(|span| span.start).unwrap_or(0);
+
+    toml_error_at(text, offset, error.message())
+}
+
+/// Parse error at byte `offset` of a TOML `text`.
+pub fn toml_error_at(text: &str, offset: usize, message: &str) -> Error {
+    let before = &text[..offset];
+
+    let i_line = before.matches('\n').count();
+    let i_col = offset - before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line = text.lines().nth(i_line).unwrap_or("");
+
+    Error::parse(i_line, i_col, line, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+    use crate::error::Error;
+    use crate::solver::Params;
+
+    #[test]
+    fn parse_inputs_root() {
+        let config = Config::from_toml("inputs_root = \"/tmp/inputs\"").unwrap();
+
+        assert_eq!(config.inputs_root, Some("/tmp/inputs".into()));
+        assert_eq!(
+            config.inputs_root(Some("flag".as_ref())),
+            std::path::PathBuf::from("flag")
+        );
+    }
+
+    #[test]
+    fn parse_params() {
+        let config = Config::from_toml("[params.2023.2]\nbag = \"1 red\"").unwrap();
+
+        assert_eq!(config.params(2023, 2)["bag"], "1 red");
+        assert!(config.params(2023, 1).is_empty());
+
+        let overrides = Params::from([("bag".to_string(), "2 red".to_string())]);
+
+        assert_eq!(config.params_with(2023, 2, &overrides)["bag"], "2 red");
+        assert_eq!(config.params_with(2023, 2, &Params::new())["bag"], "1 red");
+    }
+
+    #[test]
+    fn reports_unknown_key() {
+        match Config::from_toml("# Inputs\ninput_root = \"inputs\"") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            _ => panic!("Expected parse error"),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    /// Malformed input, `line` and `column` are 1-based and `text` is the offending part.
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// No input file for `year` and `day` in any of the `searched` paths.
+    InputNotFound {
+        year: u16,
+        day: u8,
+        searched: Vec<std::path::PathBuf>,
+    },
+    UnsupportedYear(u16),
+    /// Day not implemented, by year and day.
+    UnsupportedDay(u16, u8),
+    UnsupportedPart(String),
+    /// Feature not supported by a solver, e.g., explaining its answers.
+    Unsupported(String),
+    Overflow(String),
+    /// A new day could not be scaffolded, e.g., as it would overwrite existing work.
+    Scaffold(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn io(path: &std::path::Path, source: std::io::Error) -> Error {
+        Error::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    /// Parse error of `text`, found at 0-based `i_line` and byte offset `i_col`.
+    pub fn parse(i_line: usize, i_col: usize, text: &str, message: &str) -> Error {
+        Error::Parse {
+            line: i_line + 1,
+            column: i_col + 1,
+            text: text.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => {
+                write!(f, "I/O error on {}: {}", path.display(), source)
+            }
+            Error::Parse {
+                line,
+                column,
+                text,
+                message,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {}: \"{}\"",
+                line, column, message, text
+            ),
+            Error::InputNotFound {
+                year,
+                day,
+                searched,
+            } => {
+                write!(f, "No input found for {} day {}, looked in:", year, day)?;
+
+                for path in searched {
+                    write!(f, "\n  {}", path.display())?;
+                }
+
+                Ok(())
+            }
+            Error::UnsupportedYear(year) => write!(f, "Year not implemented: {}", year),
+            Error::UnsupportedDay(year, day) => {
+                write!(f, "Day not implemented: {} day {}", year, day)
+            }
+            Error::UnsupportedPart(part) => write!(f, "Unsupported part: {}, must be 1-2", part),
+            Error::Unsupported(what) => write!(f, "Not supported: {}", what),
+            Error::Overflow(what) => write!(f, "Overflow when computing {}", what),
+            Error::Scaffold(reason) => write!(f, "Cannot create day: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Serializable details of an `Error`, for machine-readable output.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize)]
+pub struct ErrorRecord {
+    pub kind: &'static str,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+}
+
+impl From<&Error> for ErrorRecord {
+    fn from(error: &Error) -> ErrorRecord {
+        let kind = match error {
+            Error::Io { .. } => "io",
+            Error::Parse { .. } => "parse",
+            Error::InputNotFound { .. } => "input_not_found",
+            Error::UnsupportedYear(_) => "unsupported_year",
+            Error::UnsupportedDay(..) => "unsupported_day",
+            Error::UnsupportedPart(_) => "unsupported_part",
+            Error::Unsupported(_) => "unsupported",
+            Error::Overflow(_) => "overflow",
+            Error::Scaffold(_) => "scaffold",
+        };
+
+        let mut record = ErrorRecord {
+            kind,
+            message: error.to_string(),
+            line: None,
+            column: None,
+            text: None,
+        };
+
+        if let Error::Parse {
+            line, column, text, ..
+        } = error
+        {
+            record.line = Some(*line);
+            record.column = Some(*column);
+            record.text = Some(text.clone());
+        }
+
+        record
+    }
+}
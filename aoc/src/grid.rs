@@ -0,0 +1,458 @@
+use std::collections::VecDeque;
+use std::fmt;
+
+use crate::error::{Error, Result};
+
+/// Position in a grid as (row, column).
+pub type Pos = (usize, usize);
+
+/// Steps to the 4 orthogonal neighbours, as (row, column) offsets.
+pub const STEPS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Steps to the 8 orthogonal and diagonal neighbours, as (row, column) offsets.
+pub const STEPS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    n_rows: usize,
+    n_cols: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(n_rows: usize, n_cols: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; n_rows * n_cols],
+            n_rows,
+            n_cols,
+        }
+    }
+
+    /// Grid of the given rows, `None` if they are of different lengths.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let n_rows = rows.len();
+        let n_cols = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        if rows.iter().any(|row| row.len() != n_cols) {
+            return None;
+        }
+
+        Some(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            n_rows,
+            n_cols,
+        })
+    }
+
+    /// Parses a grid of a cell per character and a row per line, `cell` returning
+    /// `None` for an invalid character.
+    pub fn parse<F>(text: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells: Vec<T> = Vec::new();
+        let mut n_rows: usize = 0;
+        let mut n_cols: Option<usize> = None;
+
+        for (i_line, line) in text.lines().enumerate() {
+            let n_chars = line.chars().count();
+
+            if let Some(n_cols) = n_cols {
+                if n_chars != n_cols {
+                    return Err(Error::parse(
+                        i_line,
+                        n_chars.min(n_cols),
+                        line,
+                        &format!("Row length differs from first row of {}", n_cols),
+                    ));
+                }
+            }
+
+            for (i_col, c) in line.chars().enumerate() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        return Err(Error::parse(i_line, i_col, &c.to_string(), "Invalid cell"))
+                    }
+                }
+            }
+
+            n_cols = Some(n_chars);
+            n_rows += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            n_rows,
+            n_cols: n_cols.unwrap_or(0),
+        })
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 < self.n_rows && pos.1 < self.n_cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        match self.contains(pos) {
+            true => Some(&self.cells[pos.0 * self.n_cols + pos.1]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        match self.contains(pos) {
+            true => Some(&mut self.cells[pos.0 * self.n_cols + pos.1]),
+            false => None,
+        }
+    }
+
+    /// Position one `step` away from `pos`, `None` if outside the grid.
+    pub fn step(&self, pos: Pos, step: (isize, isize)) -> Option<Pos> {
+        let row = pos.0.checked_add_signed(step.0)?;
+        let col = pos.1.checked_add_signed(step.1)?;
+
+        match self.contains((row, col)) {
+            true => Some((row, col)),
+            false => None,
+        }
+    }
+
+    /// Orthogonal neighbours of `pos` within the grid.
+    pub fn neighbours_4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        STEPS_4.iter().filter_map(move |step| self.step(pos, *step))
+    }
+
+    /// Orthogonal and diagonal neighbours of `pos` within the grid.
+    pub fn neighbours_8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        STEPS_8.iter().filter_map(move |step| self.step(pos, *step))
+    }
+
+    /// Positions from `pos` (included) repeatedly taking `step` until leaving the grid.
+    pub fn ray(&self, pos: Pos, step: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(Some(pos).filter(|pos| self.contains(*pos)), move |pos| {
+            self.step(*pos, step)
+        })
+    }
+
+    /// Positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let n_cols = self.n_cols;
+
+        (0..self.n_rows).flat_map(move |row| (0..n_cols).map(move |col| (row, col)))
+    }
+
+    /// Cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Cells of a row, panics if the row is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        if row >= self.n_rows {
+            panic!(
+                "Row {} outside grid of {}x{}",
+                row, self.n_rows, self.n_cols
+            );
+        }
+
+        &self.cells[row * self.n_cols..(row + 1) * self.n_cols]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.n_rows).map(|row| self.row(row))
+    }
+
+    /// Cells of a column, panics if the column is outside the grid.
+    pub fn col(&self, col: usize) -> impl Iterator<Item = &T> {
+        if col >= self.n_cols {
+            panic!(
+                "Column {} outside grid of {}x{}",
+                col, self.n_rows, self.n_cols
+            );
+        }
+
+        self.cells.iter().skip(col).step_by(self.n_cols)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.n_cols).map(|col| self.col(col))
+    }
+
+    /// Diagonals going down and right, starting from the bottom left one.
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let starts = (0..self.n_rows)
+            .rev()
+            .map(|row| (row, 0))
+            .chain((1..self.n_cols).map(|col| (0, col)));
+
+        starts.map(|start| self.ray(start, (1, 1)).map(|pos| &self[pos]).collect())
+    }
+
+    /// Diagonals going down and left, starting from the top left one.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let starts = (0..self.n_cols)
+            .map(|col| (0, col))
+            .chain((1..self.n_rows).map(|row| (row, self.n_cols.saturating_sub(1))));
+
+        starts.map(|start| self.ray(start, (1, -1)).map(|pos| &self[pos]).collect())
+    }
+
+    /// Positions of the cells matching `predicate`, row by row.
+    pub fn find_all<'a, P>(&'a self, mut predicate: P) -> impl Iterator<Item = Pos> + 'a
+    where
+        P: FnMut(&T) -> bool + 'a,
+    {
+        self.iter()
+            .filter(move |(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Positions of the region of orthogonally connected cells matching `predicate`,
+    /// reached from `start`, in breadth-first order.
+    pub fn flood_fill<P>(&self, start: Pos, mut predicate: P) -> Vec<Pos>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut filled: Vec<Pos> = Vec::new();
+
+        if !self.get(start).is_some_and(&mut predicate) {
+            return filled;
+        }
+
+        let mut visited = vec![false; self.cells.len()];
+        let mut queue = VecDeque::from([start]);
+
+        visited[start.0 * self.n_cols + start.1] = true;
+
+        while let Some(pos) = queue.pop_front() {
+            filled.push(pos);
+
+            for next in self.neighbours_4(pos) {
+                let i_next = next.0 * self.n_cols + next.1;
+
+                if !visited[i_next] && predicate(&self[next]) {
+                    visited[i_next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        filled
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+        }
+    }
+
+    /// Grid mirrored over its main diagonal, i.e., rows become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.remap(self.n_cols, self.n_rows, |(row, col)| (col, row))
+    }
+
+    /// Grid rotated a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let n_rows = self.n_rows;
+
+        self.remap(self.n_cols, self.n_rows, |(row, col)| {
+            (n_rows - 1 - col, row)
+        })
+    }
+
+    /// Grid rotated a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let n_cols = self.n_cols;
+
+        self.remap(self.n_cols, self.n_rows, |(row, col)| {
+            (col, n_cols - 1 - row)
+        })
+    }
+
+    /// Grid of the given size, each cell taken from the position `source` of this grid.
+    fn remap<F>(&self, n_rows: usize, n_cols: usize, source: F) -> Grid<T>
+    where
+        T: Clone,
+        F: Fn(Pos) -> Pos,
+    {
+        let cells = (0..n_rows)
+            .flat_map(|row| (0..n_cols).map(move |col| (row, col)))
+            .map(|pos| self[source(pos)].clone())
+            .collect();
+
+        Grid {
+            cells,
+            n_rows,
+            n_cols,
+        }
+    }
+}
+
+impl<T> std::ops::Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => panic!(
+                "Position {:?} outside grid of {}x{}",
+                pos, self.n_rows, self.n_cols
+            ),
+        }
+    }
+}
+
+impl<T> std::ops::IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (n_rows, n_cols) = (self.n_rows, self.n_cols);
+
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => panic!("Position {:?} outside grid of {}x{}", pos, n_rows, n_cols),
+        }
+    }
+}
+
+/// Prints a row per line, with the cells side by side.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::error::Error;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef", Some).unwrap()
+    }
+
+    #[test]
+    fn parse_and_index() {
+        let grid = grid();
+
+        assert_eq!((grid.n_rows(), grid.n_cols()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn reports_ragged_rows() {
+        match Grid::parse("abc\nde", Some) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn neighbours_within_bounds() {
+        let grid = grid();
+
+        let mut neighbours_4: Vec<_> = grid.neighbours_4((0, 0)).collect();
+        neighbours_4.sort();
+
+        assert_eq!(neighbours_4, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn rows_cols_and_diagonals() {
+        let grid = grid();
+
+        let cols: Vec<String> = grid.cols().map(|col| col.collect()).collect();
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|cells| cells.into_iter().collect())
+            .collect();
+        let anti_diagonals: Vec<String> = grid
+            .anti_diagonals()
+            .map(|cells| cells.into_iter().collect())
+            .collect();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(cols, vec!["ad", "be", "cf"]);
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        assert_eq!(anti_diagonals, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Column 3 outside grid of 2x3")]
+    fn rejects_col_outside() {
+        grid().col(3).count();
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 2 outside grid of 2x3")]
+    fn rejects_row_outside() {
+        grid().row(2);
+    }
+
+    #[test]
+    fn transpose_and_rotate() {
+        let grid = grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn find_all_and_flood_fill() {
+        let grid = Grid::parse("..#\n.##\n#..", Some).unwrap();
+
+        let walls: Vec<_> = grid.find_all(|cell| *cell == '#').collect();
+        let mut region = grid.flood_fill((0, 0), |cell| *cell == '.');
+        region.sort();
+
+        assert_eq!(walls, vec![(0, 2), (1, 1), (1, 2), (2, 0)]);
+        assert_eq!(region, vec![(0, 0), (0, 1), (1, 0)]);
+        assert!(grid.flood_fill((0, 2), |cell| *cell == '.').is_empty());
+    }
+}
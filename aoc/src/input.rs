@@ -0,0 +1,173 @@
+use std::io::{BufRead, Read};
+use std::sync::Arc;
+
+use crate::error::{Error, Result};
+
+/// Directory of small example inputs by year, e.g., `inputs/examples/2023/day_1.txt`,
+/// relative to the crate.
+pub const EXAMPLES_DIR: &str = "inputs/examples";
+
+/// Where puzzle input is read from.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Source {
+    File(std::path::PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    /// File path, or stdin when the path is "-".
+    pub fn from_path(path: &std::path::Path) -> Source {
+        if path.as_os_str() == "-" {
+            Source::Stdin
+        } else {
+            Source::File(path.to_path_buf())
+        }
+    }
+
+    /// Input of the source, with stdin read as a whole since it can't be read again.
+    pub fn open(&self) -> Result<RawInput> {
+        match self {
+            Source::File(path) => Ok(RawInput::File(path.clone())),
+            Source::Stdin => {
+                let mut input = String::new();
+
+                std::io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|e| Error::io(std::path::Path::new("<stdin>"), e))?;
+
+                Ok(RawInput::from(input.as_str()))
+            }
+            Source::Text(text) => Ok(RawInput::from(text.as_str())),
+        }
+    }
+}
+
+/// Puzzle input before parsing, read as a whole or streamed by lines. A file
+/// is opened anew by every read, so streaming it holds no more than a line in memory, while
+/// stdin has been read as a whole, see [`Source::open`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum RawInput {
+    File(std::path::PathBuf),
+    Text(Arc<str>),
+}
+
+impl RawInput {
+    pub fn read_to_string(&self) -> Result<String> {
+        match self {
+            RawInput::File(path) => std::fs::read_to_string(path).map_err(|e| Error::io(path, e)),
+            RawInput::Text(text) => Ok(text.to_string()),
+        }
+    }
+
+    /// Lines without their line endings, read through a buffer from a file.
+    pub fn lines(&self) -> Result<Box<dyn Iterator<Item = Result<String>> + '_>> {
+        match self {
+            RawInput::File(path) => {
+                let file = std::fs::File::open(path).map_err(|e| Error::io(path, e))?;
+                let lines = std::io::BufReader::new(file)
+                    .lines()
+                    .map(move |line| line.map_err(|e| Error::io(path, e)));
+
+                Ok(Box::new(lines))
+            }
+            RawInput::Text(text) => Ok(Box::new(text.lines().map(|line| Ok(line.to_string())))),
+        }
+    }
+}
+
+impl From<&str> for RawInput {
+    fn from(text: &str) -> RawInput {
+        RawInput::Text(Arc::from(text))
+    }
+}
+
+/// Paths searched for the input of a day, e.g., `inputs/2023/day_1.txt` then `inputs/day_1.txt`.
+pub fn input_paths(root: &std::path::Path, year: u16, day: u8) -> Vec<std::path::PathBuf> {
+    let file_name = format!("day_{}.txt", day);
+
+    vec![
+        root.join(year.to_string()).join(&file_name),
+        root.join(file_name),
+    ]
+}
+
+pub fn find_input(root: &std::path::Path, year: u16, day: u8) -> Result<std::path::PathBuf> {
+    let searched = input_paths(root, year, day);
+
+    match searched.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(Error::InputNotFound {
+            year,
+            day,
+            searched,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_input, RawInput, Source};
+    use crate::error::Error;
+    use crate::utils::test::temp_path;
+
+    #[test]
+    fn source_from_path() {
+        assert_eq!(Source::from_path("-".as_ref()), Source::Stdin);
+        assert_eq!(
+            Source::from_path("inputs/day_1.txt".as_ref()),
+            Source::File("inputs/day_1.txt".into())
+        );
+    }
+
+    #[test]
+    fn find_input_lists_searched_paths() {
+        let root = std::path::Path::new("no_such_inputs");
+
+        match find_input(root, 2023, 1) {
+            Err(Error::InputNotFound {
+                year,
+                day,
+                searched,
+            }) => {
+                assert_eq!((year, day), (2023, 1));
+                assert_eq!(
+                    searched,
+                    vec![root.join("2023/day_1.txt"), root.join("day_1.txt")]
+                );
+            }
+            _ => panic!("Expected input not found"),
+        }
+    }
+
+    #[test]
+    fn read_text() {
+        let input = Source::Text("1abc2\r\n3".to_string()).open().unwrap();
+
+        assert_eq!(input.read_to_string().unwrap(), "1abc2\r\n3");
+        assert_eq!(
+            input
+                .lines()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            ["1abc2", "3"]
+        );
+    }
+
+    #[test]
+    fn stream_file() {
+        let path = temp_path("input_stream_file.txt");
+        std::fs::write(&path, "1abc2\r\n3\n").unwrap();
+
+        let input = RawInput::File(path.clone());
+        let lines = input.lines().unwrap().collect::<Result<Vec<_>, _>>();
+        let text = input.read_to_string();
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(lines.unwrap(), ["1abc2", "3"]);
+        assert_eq!(text.unwrap(), "1abc2\r\n3\n");
+        assert!(matches!(input.lines(), Err(Error::Io { .. })));
+    }
+}
@@ -0,0 +1,54 @@
+//! Solvers of the Advent of Code puzzles, usable as a library or through the `aoc` cli.
+//!
+//! The days of a year are in its `year_YYYY` module, each implementing [`Solver`], e.g.,
+//! to solve a day directly:
+//!
+//! ```
+//! use aoc::year_2023::day_1::Day1;
+//! use aoc::Solver;
+//!
+//! let lines = Day1.parse("1abc2\npqr3stu8vwx").unwrap();
+//!
+//! assert_eq!(Day1.part_one(&lines).unwrap(), 50.into());
+//! ```
+//!
+//! Or look it up by year and day in the [`registry`] of all days:
+//!
+//! ```
+//! use aoc::input::RawInput;
+//! use aoc::Part;
+//!
+//! let registry = aoc::registry();
+//! let solver = registry.get(2023, 1).unwrap();
+//! let input = solver.parse_any(&RawInput::from("1abc2")).unwrap();
+//!
+//! assert_eq!(solver.solve_any(input.as_ref(), Part::One).unwrap(), 12.into());
+//! ```
+
+pub mod answer;
+pub mod answer_store;
+pub mod bench;
+pub mod config;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod scaffold;
+pub mod solver;
+pub mod types;
+pub mod utils;
+pub mod year_2023;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use solver::{AnySolver, Meta, Registry, Solver};
+pub use types::Part;
+
+/// All implemented days of every year.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    year_2023::register(&mut registry);
+
+    registry
+}
@@ -0,0 +1,429 @@
+use clap::{CommandFactory, Parser, Subcommand};
+use std::process::ExitCode;
+
+use aoc::answer::Answer;
+use aoc::answer_store::{AnswerStore, Verdict};
+use aoc::bench::{Baseline, Measurement};
+use aoc::config::Config;
+use aoc::error::{Error, Result};
+use aoc::input::{find_input, RawInput, Source};
+use aoc::output::{Format, RunResult, Table};
+use aoc::solver::{AnySolver, Meta, Params};
+use aoc::types::{DaySelection, Part, PartSelection};
+use aoc::{bench, output, registry, scaffold};
+
+#[derive(Parser)]
+#[command(subcommand_negates_reqs = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Input file or "-" for stdin, by default YYYY/day_N.txt in the inputs root
+    #[arg(short, long, global = true)]
+    input: Option<std::path::PathBuf>,
+
+    /// Input given inline instead of as a file
+    #[arg(long, global = true, conflicts_with = "input")]
+    input_text: Option<String>,
+
+    /// Directory of inputs, overrides INPUTS_ROOT and the config file [default: inputs]
+    #[arg(long, global = true)]
+    inputs_root: Option<std::path::PathBuf>,
+
+    /// Config file [default: aoc.toml if present]
+    #[arg(long, global = true)]
+    config: Option<std::path::PathBuf>,
+
+    /// Known answers file, overrides the config file [default: answers.toml]
+    #[arg(long, global = true)]
+    answers: Option<std::path::PathBuf>,
+
+    /// Year of the puzzles [default: latest implemented year]
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+
+    /// Day, range or list of days, e.g., 3, 1..=4 or 1,3
+    #[arg(short, long, required_unless_present_any = ["list", "all"], conflicts_with = "all")]
+    day: Option<DaySelection>,
+
+    /// Part 1, 2 or both
+    #[arg(short, long, default_value = "both")]
+    part: PartSelection,
+
+    /// Run every registered day of the year
+    #[arg(short, long)]
+    all: bool,
+
+    /// Parameter of the day, overriding the config file, e.g., bag="12 red, 13 green, 14 blue"
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    /// Verify answers against the known answers
+    #[arg(long, conflicts_with = "bench")]
+    verify: bool,
+
+    /// Output format of the answers
+    #[arg(short, long, value_enum, default_value_t = Format::Table, conflicts_with = "bench")]
+    output: Format,
+
+    /// Benchmark the parse and solve phases instead of printing answers
+    #[arg(short, long)]
+    bench: bool,
+
+    /// Explain how the answers are found instead of printing them, for the days that support it
+    #[arg(long, conflicts_with_all = ["bench", "verify", "output"])]
+    explain: bool,
+
+    /// Iterations per phase when benchmarking
+    #[arg(long, default_value_t = 20, value_parser = clap::value_parser!(u32).range(1..), requires = "bench")]
+    iterations: u32,
+
+    /// Compare the benchmark against a baseline file
+    #[arg(long, requires = "bench")]
+    baseline: Option<std::path::PathBuf>,
+
+    /// Save the benchmark as a baseline file
+    #[arg(long, requires = "bench")]
+    save_baseline: Option<std::path::PathBuf>,
+
+    /// List the registered days, of the year if given, and exit
+    #[arg(short, long)]
+    list: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Record the accepted answer of a day and part in the known answers
+    Record {
+        #[arg(short, long)]
+        day: u8,
+
+        #[arg(short, long, value_parser = clap::value_parser!(Part))]
+        part: Part,
+
+        /// Answer to record, by default solved from the input
+        #[arg(short, long, value_parser = <Answer as std::str::FromStr>::from_str)]
+        answer: Option<Answer>,
+
+        /// Overwrite a different known answer
+        #[arg(long)]
+        force: bool,
+    },
+    /// Generate the module, placeholder input and example of a new day and register it
+    New {
+        #[arg(short, long)]
+        day: u8,
+
+        /// Puzzle title [default: Day N]
+        #[arg(short, long)]
+        title: Option<String>,
+    },
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let log_level = if args.verbose {
+        log::LevelFilter::Debug
+    } else {
+        log::LevelFilter::Info
+    };
+
+    env_logger::builder()
+        .filter_level(log_level)
+        .format_target(false)
+        .format_timestamp(None)
+        .init();
+
+    match run(args) {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: Args) -> Result<ExitCode> {
+    let mut registry = registry();
+
+    if args.list {
+        let solvers = registry
+            .iter()
+            .filter(|solver| args.year.is_none_or(|year| solver.meta().year == year));
+
+        for solver in solvers {
+            let meta = solver.meta();
+
+            println!("{} day {}: {}", meta.year, meta.day, meta.title);
+        }
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let year = match args.year {
+        Some(year) => year,
+        None => *registry.years().last().expect("No days registered"),
+    };
+
+    let config = Config::load_or_default(args.config.as_deref())?;
+    let params: Params = args.params.iter().cloned().collect();
+    let answers_path = config.answers_path(args.answers.as_deref());
+
+    if let Some(Command::Record {
+        day,
+        part,
+        answer,
+        force,
+    }) = &args.command
+    {
+        registry.configure(year, *day, &config.params_with(year, *day, &params))?;
+
+        let solver = registry
+            .get(year, *day)
+            .ok_or(Error::UnsupportedDay(year, *day))?;
+        let meta = solver.meta();
+
+        let answer = match answer {
+            Some(answer) => answer.clone(),
+            None => {
+                let input = read_input(&args, &config, &meta)?;
+
+                solver.solve_any(solver.parse_any(&input)?.as_ref(), *part)?
+            }
+        };
+
+        let mut store = AnswerStore::load(&answers_path)?;
+
+        if let Some(known) = store.get(meta.year, meta.day, *part) {
+            if known != &answer && !force {
+                eprintln!(
+                    "error: {} day {} part {} is already recorded as {}, use --force to overwrite",
+                    meta.year, meta.day, part, known
+                );
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+
+        store.record(meta.year, meta.day, *part, answer.clone());
+        store.save(&answers_path)?;
+
+        println!(
+            "Recorded {} day {} part {}: {}",
+            meta.year, meta.day, part, answer
+        );
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if let Some(Command::New { day, title }) = &args.command {
+        let title = match title {
+            Some(title) => title.clone(),
+            None => format!("Day {}", day),
+        };
+
+        let written = scaffold::new_day(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+            &config.inputs_root(args.inputs_root.as_deref()),
+            year,
+            *day,
+            &title,
+        )?;
+
+        for path in written {
+            println!("Wrote {}", path.display());
+        }
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if registry.year(year).next().is_none() {
+        return Err(Error::UnsupportedYear(year));
+    }
+
+    let days: Vec<u8> = match &args.day {
+        Some(selection) => selection.days().to_vec(),
+        None => registry
+            .year(year)
+            .map(|solver| solver.meta().day)
+            .collect(),
+    };
+
+    let parts = args.part.parts();
+
+    if days.len() > 1 && (args.input.is_some() || args.input_text.is_some()) {
+        Args::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "Running several days reads inputs from the inputs root, not --input",
+            )
+            .exit();
+    }
+
+    if days.len() > 1 && !args.params.is_empty() {
+        Args::command()
+            .error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "Running several days takes parameters from the config file, not --param",
+            )
+            .exit();
+    }
+
+    for day in &days {
+        registry.configure(year, *day, &config.params_with(year, *day, &params))?;
+    }
+
+    let solvers = days
+        .iter()
+        .map(|day| {
+            registry
+                .get(year, *day)
+                .ok_or(Error::UnsupportedDay(year, *day))
+        })
+        .collect::<Result<Vec<&dyn AnySolver>>>()?;
+
+    if args.explain {
+        for solver in solvers {
+            let meta = solver.meta();
+            let input = read_input(&args, &config, &meta)?;
+            let parsed = solver.parse_any(&input)?;
+
+            for part in parts {
+                println!("{} day {} part {}:", meta.year, meta.day, part);
+                print!("{}", solver.explain_any(parsed.as_ref(), *part)?);
+            }
+        }
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if args.bench {
+        let mut measurements: Vec<Measurement> = Vec::new();
+
+        for solver in solvers {
+            let input = read_input(&args, &config, &solver.meta())?;
+
+            measurements.extend(bench::bench(
+                solver,
+                &input,
+                parts,
+                args.iterations as usize,
+            )?);
+        }
+
+        let baseline = match args.baseline {
+            Some(path) => Some(Baseline::load(&path)?),
+            None => None,
+        };
+
+        print_bench(&measurements, baseline);
+
+        if let Some(path) = args.save_baseline {
+            Baseline::from_measurements(&measurements).save(&path)?;
+        }
+
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let store = match args.verify {
+        true => Some(AnswerStore::load(&answers_path)?),
+        false => None,
+    };
+
+    let mut results: Vec<RunResult> = Vec::new();
+
+    for solver in solvers {
+        let read = || read_input(&args, &config, &solver.meta());
+
+        results.extend(output::run_day(solver, read, parts, store.as_ref()));
+    }
+
+    let n_errors = results.iter().filter(|r| r.error.is_some()).count();
+    let n_wrong = results
+        .iter()
+        .filter(|r| r.status == Some(Verdict::Wrong))
+        .count();
+
+    match args.output {
+        Format::Table if results.len() == 1 && !args.verify => {
+            match (&results[0].answer, &results[0].error) {
+                (Some(answer), _) => println!("{}", answer),
+                (None, Some(error)) => eprintln!("error: {}", error.message),
+                (None, None) => {}
+            }
+        }
+        Format::Table => print!("{}", output::to_table(&results, args.verify)),
+        Format::Json => print!("{}", output::to_json(&results)),
+        Format::Csv => print!("{}", output::to_csv(&results)),
+    }
+
+    if n_wrong > 0 {
+        eprintln!("error: {} wrong answer(s)", n_wrong);
+    }
+
+    if n_errors > 0 || n_wrong > 0 {
+        return Ok(ExitCode::FAILURE);
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn parse_param(text: &str) -> std::result::Result<(String, String), String> {
+    match text.split_once('=') {
+        Some((key, value)) => Ok((key.trim().to_string(), value.to_string())),
+        None => Err(format!("expected KEY=VALUE, got \"{}\"", text)),
+    }
+}
+
+fn read_input(args: &Args, config: &Config, meta: &Meta) -> Result<RawInput> {
+    let source = match (&args.input_text, &args.input) {
+        (Some(text), _) => Source::Text(text.clone()),
+        (None, Some(path)) => Source::from_path(path),
+        (None, None) => {
+            let inputs_root = config.inputs_root(args.inputs_root.as_deref());
+
+            Source::File(find_input(&inputs_root, meta.year, meta.day)?)
+        }
+    };
+
+    log::debug!("Reading input from {:?}", source);
+
+    source.open()
+}
+
+fn print_bench(measurements: &[Measurement], baseline: Option<Baseline>) {
+    let mut header = vec!["Year", "Day", "Phase", "Min", "Median", "Mean", "Stddev"];
+
+    if baseline.is_some() {
+        header.push("Change");
+    }
+
+    let mut table = Table::new(&header);
+
+    for measurement in measurements {
+        let stats = &measurement.stats;
+        let mut row = vec![
+            measurement.year.to_string(),
+            measurement.day.to_string(),
+            measurement.phase.to_string(),
+            format!("{:.2?}", stats.min),
+            format!("{:.2?}", stats.median),
+            format!("{:.2?}", stats.mean),
+            format!("{:.2?}", stats.stddev),
+        ];
+
+        if let Some(baseline) = &baseline {
+            row.push(match baseline.change(measurement) {
+                Some(change) => format!("{:+.1}%", change * 100.0),
+                None => "-".to_string(),
+            });
+        }
+
+        table.push(row);
+    }
+
+    print!("{}", table);
+}
@@ -0,0 +1,325 @@
+use serde::Serialize;
+use std::fmt;
+use std::time::Instant;
+
+use crate::answer::Answer;
+use crate::answer_store::{AnswerStore, Verdict};
+use crate::error::{ErrorRecord, Result};
+use crate::input::RawInput;
+use crate::solver::AnySolver;
+use crate::types::Part;
+
+#[derive(clap::ValueEnum, PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Outcome of solving one part of a day, timings are in nanoseconds.
+#[derive(Serialize, Debug)]
+pub struct RunResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub title: &'static str,
+    pub answer: Option<Answer>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    pub status: Option<Verdict>,
+    pub error: Option<ErrorRecord>,
+}
+
+/// Solves the parts of a day, capturing errors and timings per part, where reading the
+/// input counts towards parsing, and verifying the answers if given the known ones.
+pub fn run_day(
+    solver: &dyn AnySolver,
+    read_input: impl FnOnce() -> Result<RawInput>,
+    parts: &[Part],
+    store: Option<&AnswerStore>,
+) -> Vec<RunResult> {
+    let meta = solver.meta();
+
+    log::debug!("Solving {} day {}: {}", meta.year, meta.day, meta.title);
+
+    let result = |part: Part| RunResult {
+        year: meta.year,
+        day: meta.day,
+        part,
+        title: meta.title,
+        answer: None,
+        parse_ns: None,
+        solve_ns: None,
+        status: None,
+        error: None,
+    };
+
+    let start = Instant::now();
+    let parsed = read_input().and_then(|input| solver.parse_any(&input));
+    let parse_ns = start.elapsed().as_nanos();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            return parts
+                .iter()
+                .map(|part| RunResult {
+                    error: Some(ErrorRecord::from(&error)),
+                    ..result(*part)
+                })
+                .collect();
+        }
+    };
+
+    let mut results: Vec<RunResult> = Vec::new();
+
+    for part in parts {
+        let start = Instant::now();
+        let answer = solver.solve_any(parsed.as_ref(), *part);
+        let solve_ns = start.elapsed().as_nanos();
+
+        results.push(match answer {
+            Ok(answer) => RunResult {
+                status: store.map(|store| store.verify(meta.year, meta.day, *part, &answer)),
+                answer: Some(answer),
+                parse_ns: Some(parse_ns),
+                solve_ns: Some(solve_ns),
+                ..result(*part)
+            },
+            Err(error) => RunResult {
+                parse_ns: Some(parse_ns),
+                error: Some(ErrorRecord::from(&error)),
+                ..result(*part)
+            },
+        });
+    }
+
+    results
+}
+
+pub fn to_table(results: &[RunResult], with_status: bool) -> Table {
+    let mut header = vec!["Day", "Part", "Title", "Answer"];
+
+    if with_status {
+        header.push("Status");
+    }
+
+    let mut table = Table::new(&header);
+
+    for result in results {
+        let mut row = vec![
+            result.day.to_string(),
+            result.part.to_string(),
+            result.title.to_string(),
+            match (&result.answer, &result.error) {
+                (Some(answer), _) => answer.to_string(),
+                (None, Some(error)) => {
+                    format!("error: {}", error.message.lines().next().unwrap_or(""))
+                }
+                (None, None) => "-".to_string(),
+            },
+        ];
+
+        if with_status {
+            row.push(match result.status {
+                Some(status) => status.to_string(),
+                None => "-".to_string(),
+            });
+        }
+
+        table.push(row);
+    }
+
+    table
+}
+
+pub fn to_json(results: &[RunResult]) -> String {
+    serde_json::to_string_pretty(results).unwrap() + "\n"
+}
+
+pub fn to_csv(results: &[RunResult]) -> String {
+    let mut csv = String::from("year,day,part,title,answer,parse_ns,solve_ns,status,error\n");
+
+    let optional = |value: Option<String>| value.unwrap_or_default();
+
+    for result in results {
+        let fields = [
+            result.year.to_string(),
+            result.day.to_string(),
+            result.part.to_string(),
+            result.title.to_string(),
+            optional(result.answer.as_ref().map(|answer| answer.to_string())),
+            optional(result.parse_ns.map(|ns| ns.to_string())),
+            optional(result.solve_ns.map(|ns| ns.to_string())),
+            optional(result.status.map(|status| status.to_string())),
+            optional(result.error.as_ref().map(|error| error.message.clone())),
+        ];
+
+        let fields: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Plain text table with left aligned columns, sized to fit the widest cell.
+pub struct Table {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Table {
+        Table {
+            header: header.iter().map(|cell| cell.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        if row.len() != self.header.len() {
+            panic!(
+                "Row has {} cells, expected {}",
+                row.len(),
+                self.header.len()
+            );
+        }
+
+        self.rows.push(row);
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut widths: Vec<usize> = self
+            .header
+            .iter()
+            .map(|cell| cell.chars().count())
+            .collect();
+
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        for row in std::iter::once(&self.header).chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+
+            writeln!(f, "{}", cells.join("  ").trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_day, to_csv, to_json, RunResult, Table};
+    use crate::answer::Answer;
+    use crate::answer_store::{AnswerStore, Verdict};
+    use crate::error::{Error, ErrorRecord};
+    use crate::input::RawInput;
+    use crate::types::Part;
+    use crate::year_2023::day_1::Day1;
+
+    fn results() -> Vec<RunResult> {
+        vec![
+            RunResult {
+                year: 2023,
+                day: 1,
+                part: Part::One,
+                title: "Trebuchet?!",
+                answer: Some(Answer::from(53080)),
+                parse_ns: Some(1500),
+                solve_ns: Some(200),
+                status: Some(Verdict::Correct),
+                error: None,
+            },
+            RunResult {
+                year: 2023,
+                day: 2,
+                part: Part::Two,
+                title: "Cube Conundrum",
+                answer: None,
+                parse_ns: None,
+                solve_ns: None,
+                status: None,
+                error: Some(ErrorRecord::from(&Error::parse(
+                    0,
+                    5,
+                    "1, x",
+                    "Invalid number",
+                ))),
+            },
+        ]
+    }
+
+    #[test]
+    fn runs_and_verifies_day() {
+        let store = AnswerStore::from_toml("[2023.day_1]\npart_1 = 12\n").unwrap();
+        let parts = [Part::One, Part::Two];
+
+        let results = run_day(&Day1, || Ok(RawInput::from("1abc2")), &parts, Some(&store));
+
+        assert_eq!(results[0].answer, Some(Answer::from(12)));
+        assert_eq!(results[0].status, Some(Verdict::Correct));
+        assert_eq!(results[1].status, Some(Verdict::Unknown));
+        assert!(results
+            .iter()
+            .all(|r| r.parse_ns.is_some() && r.error.is_none()));
+
+        let failed = run_day(&Day1, || Ok(RawInput::from("abc")), &parts, None);
+
+        assert!(failed
+            .iter()
+            .all(|r| r.answer.is_none() && r.error.as_ref().unwrap().kind == "parse"));
+    }
+
+    #[test]
+    fn aligns_columns() {
+        let mut table = Table::new(&["Day", "Answer"]);
+        table.push(vec!["1".to_string(), "53080".to_string()]);
+        table.push(vec!["12".to_string(), "7".to_string()]);
+
+        assert_eq!(table.to_string(), "Day  Answer\n1    53080\n12   7\n");
+    }
+
+    #[test]
+    fn results_as_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&results())).unwrap();
+
+        assert_eq!(json[0]["answer"], 53080);
+        assert_eq!(json[0]["part"], 1);
+        assert_eq!(json[0]["status"], "correct");
+        assert!(json[0]["error"].is_null());
+        assert!(json[1]["answer"].is_null());
+        assert_eq!(json[1]["error"]["kind"], "parse");
+        assert_eq!(json[1]["error"]["line"], 1);
+        assert_eq!(json[1]["error"]["column"], 6);
+    }
+
+    #[test]
+    fn results_as_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,part,title,answer,parse_ns,solve_ns,status,error\n\
+             2023,1,1,Trebuchet?!,53080,1500,200,correct,\n\
+             2023,2,2,Cube Conundrum,,,,,\"Parse error at line 1, column 6: Invalid number: \"\"1, x\"\"\"\n"
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use std::io::Write;
+
+use crate::error::{Error, Result};
+use crate::input::{input_paths, EXAMPLES_DIR};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs");
+const YEAR_TEMPLATE: &str = include_str!("../templates/year.rs");
+
+/// Generates a new day in the crate at `crate_dir`, i.e., the `day_N` module,
+/// placeholder input and example files, and its registration in the `year_YYYY`
+/// module, which is created and registered in `lib.rs` for a new year.
+///
+/// Nothing is written if any of it already exists. Returns the paths written.
+pub fn new_day(
+    crate_dir: &std::path::Path,
+    inputs_root: &std::path::Path,
+    year: u16,
+    day: u8,
+    title: &str,
+) -> Result<Vec<std::path::PathBuf>> {
+    let src = crate_dir.join("src");
+    let year_dir = src.join(format!("year_{}", year));
+    let module = year_dir.join(format!("day_{}.rs", day));
+    let year_module = year_dir.join("mod.rs");
+    let lib = src.join("lib.rs");
+    let example = crate_dir
+        .join(EXAMPLES_DIR)
+        .join(year.to_string())
+        .join(format!("day_{}.txt", day));
+
+    let inputs = input_paths(inputs_root, year, day);
+    let input = inputs[0].clone();
+
+    for path in inputs.iter().chain([&module, &example]) {
+        if path.exists() {
+            return Err(Error::Scaffold(format!(
+                "Refusing to overwrite {}",
+                path.display()
+            )));
+        }
+    }
+
+    let (year_text, lib_text) = if year_module.exists() {
+        let year_text = read(&year_module)?;
+        let year_text = declare(
+            &year_text,
+            &format!("day_{}", day),
+            &format!("    registry.register(day_{}::Day{});", day, day),
+        )?;
+
+        (year_text, None)
+    } else {
+        let lib_text = declare(
+            &read(&lib)?,
+            &format!("year_{}", year),
+            &format!("    year_{}::register(&mut registry);", year),
+        )?;
+
+        (render(YEAR_TEMPLATE, year, day, title), Some(lib_text))
+    };
+
+    write_new(&module, &render(DAY_TEMPLATE, year, day, title))?;
+    write_new(&input, "")?;
+    write_new(&example, "")?;
+    std::fs::write(&year_module, year_text).map_err(|e| Error::io(&year_module, e))?;
+
+    let mut written = vec![module, input, example, year_module];
+
+    if let Some(lib_text) = lib_text {
+        std::fs::write(&lib, lib_text).map_err(|e| Error::io(&lib, e))?;
+        written.push(lib);
+    }
+
+    Ok(written)
+}
+
+/// A module from a template.
+fn render(template: &str, year: u16, day: u8, title: &str) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{title}", &format!("{:?}", title))
+}
+
+fn read(path: &std::path::Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|e| Error::io(path, e))
+}
+
+/// Adds `pub mod <module>;` and a `register` line, after the last one, to the source of
+/// a module registering its submodules.
+fn declare(source: &str, module: &str, register: &str) -> Result<String> {
+    let mut lines: Vec<String> = source.lines().map(String::from).collect();
+
+    let mod_name = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|line| line.strip_suffix(';'))
+            .map(String::from)
+    };
+
+    let i_mods: Vec<usize> = (0..lines.len())
+        .filter(|i| mod_name(&lines[*i]).is_some())
+        .collect();
+
+    if i_mods
+        .iter()
+        .any(|i| mod_name(&lines[*i]).as_deref() == Some(module))
+    {
+        return Err(Error::Scaffold(format!("{} is already declared", module)));
+    }
+
+    let i_register = lines
+        .iter()
+        .rposition(|line| line.contains("register(") && line.ends_with(");"));
+
+    let (i_last_mod, i_register) = match (i_mods.last(), i_register) {
+        (Some(i_last_mod), Some(i_register)) => (*i_last_mod, i_register),
+        _ => {
+            return Err(Error::Scaffold(format!(
+                "No registration found to add {} to",
+                module
+            )))
+        }
+    };
+
+    // Registration order does not matter, append it.
+    lines.insert(i_register + 1, register.to_string());
+
+    // Modules are kept sorted by name, as by rustfmt.
+    let i_mod = i_mods
+        .into_iter()
+        .find(|i| mod_name(&lines[*i]).unwrap().as_str() > module)
+        .unwrap_or(i_last_mod + 1);
+
+    lines.insert(i_mod, format!("pub mod {};", module));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+fn write_new(path: &std::path::Path, contents: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| Error::io(dir, e))?;
+    }
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(contents.as_bytes()))
+        .map_err(|e| Error::io(path, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{declare, render, DAY_TEMPLATE};
+    use crate::error::Error;
+
+    const YEAR: &str = "use crate::solver::Registry;
+
+pub mod day_1;
+pub mod day_2;
+
+pub fn register(registry: &mut Registry) {
+    registry.register(day_1::Day1);
+    registry.register(day_2::Day2);
+}
+";
+
+    fn declare_day(day: u8) -> crate::error::Result<String> {
+        declare(
+            YEAR,
+            &format!("day_{}", day),
+            &format!("    registry.register(day_{}::Day{});", day, day),
+        )
+    }
+
+    #[test]
+    fn declares_day() {
+        let year = declare_day(10).unwrap();
+
+        assert!(year.contains("pub mod day_1;\npub mod day_10;\npub mod day_2;\n"));
+        assert!(year.contains(
+            "    registry.register(day_2::Day2);\n    registry.register(day_10::Day10);\n}"
+        ));
+    }
+
+    #[test]
+    fn refuses_declared_day() {
+        match declare_day(2) {
+            Err(Error::Scaffold(reason)) => assert_eq!(reason, "day_2 is already declared"),
+            _ => panic!("Expected scaffold error"),
+        }
+    }
+
+    #[test]
+    fn renders_template() {
+        let module = render(DAY_TEMPLATE, 2023, 5, "If You Give A Seed A Fertilizer");
+
+        assert!(module.contains("pub struct Day5;"));
+        assert!(module.contains("const YEAR: u16 = 2023;"));
+        assert!(module.contains("const TITLE: &'static str = \"If You Give A Seed A Fertilizer\";"));
+        assert!(module.contains("#[cfg_attr(missing_input = \"2023_5\", ignore"));
+    }
+}
@@ -0,0 +1,317 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input::RawInput;
+use crate::types::Part;
+
+/// Named settings of a solver, e.g., from the cli or the config file.
+pub type Params = BTreeMap<String, String>;
+
+pub struct Meta {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+}
+
+pub trait Solver {
+    const YEAR: u16;
+    const DAY: u8;
+    const TITLE: &'static str;
+
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    /// Parses input read as a whole by default, days that are naturally per line may
+    /// instead stream it.
+    fn parse_input(&self, input: &RawInput) -> Result<Self::Input> {
+        self.parse(&input.read_to_string()?)
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+
+    /// Applies parameters, by default rejecting any as the solver has none.
+    fn configure(&mut self, params: &Params) -> Result<()> {
+        match params.keys().next() {
+            Some(key) => Err(Error::Unsupported(format!(
+                "parameter {} of {} day {}",
+                key,
+                Self::YEAR,
+                Self::DAY
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Human readable account of how the answer of a part is found, for debugging.
+    fn explain(&self, _input: &Self::Input, part: Part) -> Result<String> {
+        Err(Error::Unsupported(format!(
+            "explaining {} day {} part {}",
+            Self::YEAR,
+            Self::DAY,
+            part
+        )))
+    }
+}
+
+/// Type erased `Solver`, enabling solvers with different inputs to be stored side by side.
+pub trait AnySolver {
+    fn meta(&self) -> Meta;
+
+    fn parse_any(&self, input: &RawInput) -> Result<Box<dyn Any>>;
+
+    fn solve_any(&self, parsed: &dyn Any, part: Part) -> Result<Answer>;
+
+    fn explain_any(&self, parsed: &dyn Any, part: Part) -> Result<String>;
+
+    fn configure_any(&mut self, params: &Params) -> Result<()>;
+}
+
+impl<S> AnySolver for S
+where
+    S: Solver,
+    S::Input: 'static,
+{
+    fn meta(&self) -> Meta {
+        Meta {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+        }
+    }
+
+    fn parse_any(&self, input: &RawInput) -> Result<Box<dyn Any>> {
+        Ok(Box::new(self.parse_input(input)?))
+    }
+
+    fn solve_any(&self, parsed: &dyn Any, part: Part) -> Result<Answer> {
+        match part {
+            Part::One => self.part_one(downcast::<S>(parsed)),
+            Part::Two => self.part_two(downcast::<S>(parsed)),
+        }
+    }
+
+    fn explain_any(&self, parsed: &dyn Any, part: Part) -> Result<String> {
+        self.explain(downcast::<S>(parsed), part)
+    }
+
+    fn configure_any(&mut self, params: &Params) -> Result<()> {
+        self.configure(params)
+    }
+}
+
+fn downcast<S: Solver>(parsed: &dyn Any) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input of another solver")
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn AnySolver>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register<S>(&mut self, solver: S) -> &mut Registry
+    where
+        S: Solver + 'static,
+    {
+        if self.get(S::YEAR, S::DAY).is_some() {
+            panic!("{} day {} already registered", S::YEAR, S::DAY);
+        }
+
+        self.solvers.push(Box::new(solver));
+        self.solvers.sort_by_key(|solver| {
+            let meta = solver.meta();
+            (meta.year, meta.day)
+        });
+
+        self
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&dyn AnySolver> {
+        self.iter().find(|solver| {
+            let meta = solver.meta();
+            meta.year == year && meta.day == day
+        })
+    }
+
+    pub fn get_mut(&mut self, year: u16, day: u8) -> Option<&mut dyn AnySolver> {
+        let solver = self.solvers.iter_mut().find(|solver| {
+            let meta = solver.meta();
+            meta.year == year && meta.day == day
+        })?;
+
+        Some(solver.as_mut())
+    }
+
+    /// Applies the parameters of a day, see [`Solver::configure`].
+    pub fn configure(&mut self, year: u16, day: u8, params: &Params) -> Result<()> {
+        self.get_mut(year, day)
+            .ok_or(Error::UnsupportedDay(year, day))?
+            .configure_any(params)
+    }
+
+    /// Years with registered days, in order.
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.iter().map(|solver| solver.meta().year).collect();
+        years.dedup();
+
+        years
+    }
+
+    /// Registered days of a year, in order.
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &dyn AnySolver> {
+        self.iter().filter(move |solver| solver.meta().year == year)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn AnySolver> {
+        self.solvers.iter().map(|solver| solver.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, Error, Params, Part, Registry, Result, Solver};
+
+    struct Length;
+    struct Words;
+    struct Lines;
+
+    impl Solver for Length {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Length";
+
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> Result<usize> {
+            Ok(input.len())
+        }
+
+        fn part_one(&self, input: &usize) -> Result<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part_two(&self, input: &usize) -> Result<Answer> {
+            Ok((*input * 2).into())
+        }
+    }
+
+    impl Solver for Words {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 1;
+        const TITLE: &'static str = "Words";
+
+        type Input = Vec<String>;
+
+        fn parse(&self, input: &str) -> Result<Vec<String>> {
+            Ok(input.split_whitespace().map(String::from).collect())
+        }
+
+        fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+            Ok(input
+                .iter()
+                .map(|word| word.len())
+                .max()
+                .unwrap_or(0)
+                .into())
+        }
+    }
+
+    impl Solver for Lines {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 2;
+        const TITLE: &'static str = "Lines";
+
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> Result<usize> {
+            Ok(input.lines().count())
+        }
+
+        fn part_one(&self, input: &usize) -> Result<Answer> {
+            Ok((*input).into())
+        }
+
+        fn part_two(&self, input: &usize) -> Result<Answer> {
+            Ok((*input).into())
+        }
+    }
+
+    #[test]
+    fn lists_registered_days_in_order() {
+        let mut registry = Registry::new();
+        registry.register(Length).register(Words).register(Lines);
+
+        let days: Vec<(u16, u8)> = registry
+            .iter()
+            .map(|solver| (solver.meta().year, solver.meta().day))
+            .collect();
+
+        assert_eq!(days, vec![(2022, 2), (2023, 1), (2023, 2)]);
+        assert_eq!(registry.years(), vec![2022, 2023]);
+        assert_eq!(registry.year(2022).count(), 1);
+    }
+
+    #[test]
+    fn looks_up_and_runs_day() {
+        let mut registry = Registry::new();
+        registry.register(Length).register(Words);
+
+        let solver = registry.get(2023, 1).unwrap();
+
+        assert_eq!(solver.meta().title, "Words");
+        let parsed = solver.parse_any(&"a bb ccc".into()).unwrap();
+
+        assert_eq!(
+            solver.solve_any(parsed.as_ref(), Part::One).unwrap(),
+            3.into()
+        );
+        assert_eq!(
+            solver.solve_any(parsed.as_ref(), Part::Two).unwrap(),
+            3.into()
+        );
+        assert!(registry.get(2023, 3).is_none());
+        assert!(registry.get(2022, 1).is_none());
+    }
+
+    #[test]
+    fn rejects_params_by_default() {
+        let mut registry = Registry::new();
+        registry.register(Length);
+
+        let params = Params::from([("n".to_string(), "1".to_string())]);
+
+        assert!(registry.configure(2023, 2, &Params::new()).is_ok());
+        assert!(matches!(
+            registry.configure(2023, 2, &params),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            registry.configure(2023, 3, &Params::new()),
+            Err(Error::UnsupportedDay(2023, 3))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "2023 day 2 already registered")]
+    fn rejects_duplicate_day() {
+        Registry::new().register(Length).register(Length);
+    }
+}
@@ -0,0 +1,136 @@
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::Error;
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl FromStr for Part {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(Error::UnsupportedPart(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+/// One or both parts, e.g., "1", "2" or "both".
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct PartSelection(Vec<Part>);
+
+impl PartSelection {
+    pub fn parts(&self) -> &[Part] {
+        &self.0
+    }
+}
+
+impl FromStr for PartSelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "both" => Ok(PartSelection(vec![Part::One, Part::Two])),
+            _ => match s.parse::<Part>() {
+                Ok(part) => Ok(PartSelection(vec![part])),
+                Err(_) => Err(format!("Unsupported part: {}, must be 1-2 or both", s)),
+            },
+        }
+    }
+}
+
+/// Days as a single day, a range or a list, e.g., "3", "1..=4", "1..5" or "1,3".
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct DaySelection(Vec<u8>);
+
+impl DaySelection {
+    pub fn days(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| {
+            day.trim()
+                .parse::<u8>()
+                .map_err(|_| format!("Unsupported day: {}", day))
+        };
+
+        let mut days: Vec<u8> = Vec::new();
+
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once("..=") {
+                days.extend(parse_day(start)?..=parse_day(end)?);
+            } else if let Some((start, end)) = item.split_once("..") {
+                days.extend(parse_day(start)?..parse_day(end)?);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+
+        if days.is_empty() {
+            return Err(format!("No days in: {}", s));
+        }
+
+        days.sort();
+        days.dedup();
+
+        Ok(DaySelection(days))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DaySelection, Part, PartSelection};
+
+    #[test]
+    fn parse_part_selection() {
+        assert_eq!(
+            "both".parse::<PartSelection>().unwrap().parts(),
+            &[Part::One, Part::Two]
+        );
+        assert_eq!("2".parse::<PartSelection>().unwrap().parts(), &[Part::Two]);
+        assert!("3".parse::<PartSelection>().is_err());
+    }
+
+    #[test]
+    fn parse_day_selection() {
+        assert_eq!("3".parse::<DaySelection>().unwrap().days(), &[3]);
+        assert_eq!(
+            "1..=4".parse::<DaySelection>().unwrap().days(),
+            &[1, 2, 3, 4]
+        );
+        assert_eq!("1..4".parse::<DaySelection>().unwrap().days(), &[1, 2, 3]);
+        assert_eq!("4,1..3".parse::<DaySelection>().unwrap().days(), &[1, 2, 4]);
+        assert!("4..1".parse::<DaySelection>().is_err());
+        assert!("one".parse::<DaySelection>().is_err());
+    }
+}
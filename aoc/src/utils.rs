@@ -0,0 +1,155 @@
+use crate::error::{Error, Result};
+
+/// Sums `values`, reporting an overflow of `what` instead of wrapping.
+pub fn checked_sum<I>(values: I, what: &str) -> Result<u64>
+where
+    I: IntoIterator<Item = u64>,
+{
+    values
+        .into_iter()
+        .try_fold(0_u64, |sum, value| sum.checked_add(value))
+        .ok_or(Error::Overflow(what.to_string()))
+}
+
+#[cfg(test)]
+pub mod test {
+    use std::io::Write;
+
+    use crate::answer::Answer;
+    use crate::answer_store::{AnswerStore, ANSWERS_FILE};
+    use crate::config::{DEFAULT_INPUTS_ROOT, INPUTS_ROOT_ENV};
+    use crate::error::{Error, Result};
+    use crate::input::{find_input, RawInput, EXAMPLES_DIR};
+    use crate::solver::AnySolver;
+    use crate::types::Part;
+
+    /// Set to fail, instead of skip, tests of full inputs that are missing.
+    pub const STRICT_INPUTS_ENV: &str = "AOC_STRICT_INPUTS";
+
+    /// Input of a day in the inputs root, the crate's `inputs` if `INPUTS_ROOT` is not set.
+    pub fn get_input_path(year: u16, day: u8) -> Result<std::path::PathBuf> {
+        let root = match std::env::var(INPUTS_ROOT_ENV) {
+            Ok(root) => std::path::PathBuf::from(root),
+            Err(_) => crate_dir().join(DEFAULT_INPUTS_ROOT),
+        };
+
+        find_input(&root, year, day)
+    }
+
+    /// Solves the full input of a day, `None` if it is missing or empty, unless in strict mode.
+    ///
+    /// The build marks the tests of such days as ignored, see `build.rs`, so this is only a
+    /// fallback for inputs removed since the build.
+    pub fn solve(solver: &dyn AnySolver, part: Part) -> Option<Answer> {
+        let meta = solver.meta();
+        let path = get_input_path(meta.year, meta.day).and_then(|path| {
+            match std::fs::metadata(&path)
+                .map_err(|e| Error::io(&path, e))?
+                .len()
+            {
+                0 => Err(Error::io(
+                    &path,
+                    std::io::Error::new(std::io::ErrorKind::UnexpectedEof, "empty input"),
+                )),
+                _ => Ok(path),
+            }
+        });
+
+        match path {
+            Ok(path) => Some(solve_file(solver, part, &path)),
+            Err(error) if is_strict() => panic!("{} (strict mode)", error),
+            Err(error) => {
+                // Written to stderr directly, as the test harness captures `eprintln!`.
+                let _ = writeln!(
+                    std::io::stderr(),
+                    "Skipping {} day {} part {}: {}",
+                    meta.year,
+                    meta.day,
+                    part,
+                    error
+                );
+                None
+            }
+        }
+    }
+
+    /// Asserts the answer of the full input against the recorded one, skipped if the input is
+    /// missing, see [`solve`].
+    pub fn assert_answer(solver: &dyn AnySolver, part: Part) {
+        if let Some(solved) = solve(solver, part) {
+            assert_eq!(solved, answer(solver, part));
+        }
+    }
+
+    fn is_strict() -> bool {
+        std::env::var(STRICT_INPUTS_ENV).is_ok_and(|value| !matches!(value.as_str(), "" | "0"))
+    }
+
+    /// Recorded answer of a solver, from the answers file shared with the cli.
+    pub fn answer(solver: &dyn AnySolver, part: Part) -> Answer {
+        recorded(&crate_dir().join(ANSWERS_FILE), solver, part)
+    }
+
+    /// Example input of a day, `day_N_part_P.txt` if a part has its own, else `day_N.txt`.
+    pub fn get_example_path(year: u16, day: u8, part: Part) -> std::path::PathBuf {
+        let dir = crate_dir().join(EXAMPLES_DIR).join(year.to_string());
+        let part_path = dir.join(format!("day_{}_part_{}.txt", day, part));
+
+        if part_path.is_file() {
+            part_path
+        } else {
+            dir.join(format!("day_{}.txt", day))
+        }
+    }
+
+    /// Solves the example input of the puzzle text, which needs no inputs root.
+    pub fn solve_example(solver: &dyn AnySolver, part: Part) -> Answer {
+        let meta = solver.meta();
+
+        solve_file(solver, part, &get_example_path(meta.year, meta.day, part))
+    }
+
+    /// Expected answer of the example input, from the answers file of the examples.
+    pub fn example_answer(solver: &dyn AnySolver, part: Part) -> Answer {
+        recorded(
+            &crate_dir().join(EXAMPLES_DIR).join(ANSWERS_FILE),
+            solver,
+            part,
+        )
+    }
+
+    /// Path of a temporary file, unique to the test process so concurrent runs don't clash.
+    pub fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("aoc_{}_{}", std::process::id(), name))
+    }
+
+    fn crate_dir() -> &'static std::path::Path {
+        std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+    }
+
+    fn solve_file(solver: &dyn AnySolver, part: Part, path: &std::path::Path) -> Answer {
+        let parsed = solver
+            .parse_any(&RawInput::File(path.to_path_buf()))
+            .unwrap();
+
+        solver.solve_any(parsed.as_ref(), part).unwrap()
+    }
+
+    fn recorded(path: &std::path::Path, solver: &dyn AnySolver, part: Part) -> Answer {
+        let meta = solver.meta();
+
+        match AnswerStore::load(path)
+            .unwrap()
+            .get(meta.year, meta.day, part)
+        {
+            Some(answer) => answer.clone(),
+            None => panic!(
+                "No answer recorded for {} day {} part {} in {}",
+                meta.year,
+                meta.day,
+                part,
+                path.display()
+            ),
+        }
+    }
+}
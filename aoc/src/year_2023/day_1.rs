@@ -0,0 +1,425 @@
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input::RawInput;
+use crate::solver::Solver;
+use crate::types::Part;
+
+/// Digits as numerals.
+pub const NUMERALS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Digits spelled out in English, as in the puzzle.
+pub const ENGLISH: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Whether a digit was written as a numeral or spelled out as a word.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum DigitSource {
+    Numeral,
+    Word,
+}
+
+/// Digit found in a line, at the byte range `span`.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Digit {
+    pub value: u32,
+    pub span: std::ops::Range<usize>,
+    pub source: DigitSource,
+}
+
+/// First and last digit of a line, the same digit if there is only one.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Calibration {
+    pub first: Digit,
+    pub last: Digit,
+}
+
+impl Calibration {
+    /// The first digit followed by the last, i.e., `first * 10 + last`.
+    pub fn value(&self) -> Option<u64> {
+        (self.first.value as u64)
+            .checked_mul(10)?
+            .checked_add(self.last.value as u64)
+    }
+}
+
+/// Recognises digits in text by a vocabulary of words and the digits they spell, matching
+/// all words at once and including overlapping ones, e.g., both "two" and "one" in "twone".
+pub struct DigitMatcher {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+    sources: Vec<DigitSource>,
+}
+
+impl DigitMatcher {
+    pub fn new<'a, I>(vocabulary: I, case_insensitive: bool) -> DigitMatcher
+    where
+        I: IntoIterator<Item = &'a (&'a str, u32)>,
+    {
+        let (words, values): (Vec<&str>, Vec<u32>) = vocabulary.into_iter().copied().unzip();
+
+        let sources = words
+            .iter()
+            .map(|word| match word.chars().all(|c| c.is_ascii_digit()) {
+                true => DigitSource::Numeral,
+                false => DigitSource::Word,
+            })
+            .collect();
+
+        let automaton = AhoCorasickBuilder::new()
+            .match_kind(MatchKind::Standard)
+            .ascii_case_insensitive(case_insensitive)
+            .build(words)
+            .expect("Vocabulary too large");
+
+        DigitMatcher {
+            automaton,
+            values,
+            sources,
+        }
+    }
+
+    /// Digits in `text`, ordered by where they end.
+    pub fn digits<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Digit> + 'a {
+        self.automaton.find_overlapping_iter(text).map(|m| Digit {
+            value: self.values[m.pattern().as_usize()],
+            span: m.range(),
+            source: self.sources[m.pattern().as_usize()],
+        })
+    }
+
+    /// First and last digit of `text`, found in a single pass.
+    pub fn calibrate(&self, text: &str) -> Option<Calibration> {
+        let mut first: Option<Digit> = None;
+        let mut last: Option<Digit> = None;
+
+        for digit in self.digits(text) {
+            if first
+                .as_ref()
+                .is_none_or(|first| digit.span.start < first.span.start)
+            {
+                first = Some(digit.clone());
+            }
+
+            if last
+                .as_ref()
+                .is_none_or(|last| digit.span.start > last.span.start)
+            {
+                last = Some(digit);
+            }
+        }
+
+        Some(Calibration {
+            first: first?,
+            last: last?,
+        })
+    }
+}
+
+/// Sums of the calibration values of every line by both parts, folded in a single pass over
+/// the lines. The input is kept, without being read, to explain the calibrations.
+pub struct Calibrations {
+    /// By numerals only, or the first line without any, by index and text, to report it.
+    pub numerals: std::result::Result<u64, (usize, String)>,
+    /// By numerals and English words.
+    pub words: u64,
+    input: RawInput,
+}
+
+impl Calibrations {
+    /// Calibrates each line, an error for a line without any digits.
+    pub fn parse(input: &RawInput) -> Result<Calibrations> {
+        let (numerals, words) = (matcher(Part::One), matcher(Part::Two));
+        let mut calibrations = Calibrations {
+            numerals: Ok(0),
+            words: 0,
+            input: input.clone(),
+        };
+
+        for (i_line, line) in input.lines()?.enumerate() {
+            let line = line?;
+            let by_words = words.calibrate(&line).ok_or(Error::parse(
+                i_line,
+                0,
+                &line,
+                "No digits found in line",
+            ))?;
+
+            log::debug!("{}: {:?}", line, by_words);
+
+            calibrations.words = add_calibration_value(calibrations.words, &by_words)?;
+
+            if let Ok(sum) = calibrations.numerals {
+                calibrations.numerals = match numerals.calibrate(&line) {
+                    Some(calibration) => Ok(add_calibration_value(sum, &calibration)?),
+                    None => Err((i_line, line)),
+                };
+            }
+        }
+
+        Ok(calibrations)
+    }
+
+    /// Sum of the calibration values by a part, an error for a line without numerals in
+    /// part one.
+    pub fn sum(&self, part: Part) -> Result<u64> {
+        match (part, &self.numerals) {
+            (Part::One, Ok(sum)) => Ok(*sum),
+            (Part::One, Err((i_line, text))) => {
+                Err(Error::parse(*i_line, 0, text, "No digits found in line"))
+            }
+            (Part::Two, _) => Ok(self.words),
+        }
+    }
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = Calibrations;
+
+    fn parse(&self, input: &str) -> Result<Calibrations> {
+        Calibrations::parse(&RawInput::from(input))
+    }
+
+    fn parse_input(&self, input: &RawInput) -> Result<Calibrations> {
+        Calibrations::parse(input)
+    }
+
+    fn part_one(&self, input: &Calibrations) -> Result<Answer> {
+        Ok(input.sum(Part::One)?.into())
+    }
+
+    fn part_two(&self, input: &Calibrations) -> Result<Answer> {
+        Ok(input.sum(Part::Two)?.into())
+    }
+
+    /// Reads the lines again to calibrate and underline their digits.
+    fn explain(&self, input: &Calibrations, part: Part) -> Result<String> {
+        let matcher = matcher(part);
+        let mut explanation = String::new();
+
+        for (i_line, line) in input.input.lines()?.enumerate() {
+            let line = line?;
+
+            explanation.push_str(&explain_line(i_line, &line, matcher.calibrate(&line)));
+        }
+
+        Ok(explanation)
+    }
+}
+
+/// Matcher of the digits of a part, numerals and, for part two, English words.
+fn matcher(part: Part) -> DigitMatcher {
+    match part {
+        Part::One => DigitMatcher::new(&NUMERALS, false),
+        Part::Two => DigitMatcher::new(NUMERALS.iter().chain(&ENGLISH), false),
+    }
+}
+
+/// Adds the calibration value of a line to the sum of the lines before it.
+fn add_calibration_value(sum: u64, calibration: &Calibration) -> Result<u64> {
+    calibration
+        .value()
+        .and_then(|value| sum.checked_add(value))
+        .ok_or(Error::Overflow("sum of calibration values".to_string()))
+}
+
+/// The line with its first and last digit underlined, e.g.:
+///
+/// ```text
+/// 1: xtwone3four => 24
+///     ^^^         first 2 (word, bytes 1..4)
+///           ^^^^  last 4 (word, bytes 7..11)
+/// ```
+fn explain_line(i_line: usize, line: &str, calibration: Option<Calibration>) -> String {
+    let prefix = format!("{}: ", i_line + 1);
+
+    let calibration = match calibration {
+        Some(calibration) => calibration,
+        None => return format!("{}{} => no digits\n", prefix, line),
+    };
+
+    let value = match calibration.value() {
+        Some(value) => value.to_string(),
+        None => "overflow".to_string(),
+    };
+
+    let underline = |digit: &Digit, label: &str| {
+        let indent = prefix.len() + line[..digit.span.start].chars().count();
+        let width = line[digit.span.clone()].chars().count();
+        let source = match digit.source {
+            DigitSource::Numeral => "numeral",
+            DigitSource::Word => "word",
+        };
+
+        format!(
+            "{:indent$}{:^<width$}{:pad$}  {} {} ({}, bytes {}..{})\n",
+            "",
+            "",
+            "",
+            label,
+            digit.value,
+            source,
+            digit.span.start,
+            digit.span.end,
+            indent = indent,
+            width = width,
+            pad = line
+                .chars()
+                .count()
+                .saturating_sub(indent - prefix.len() + width),
+        )
+    };
+
+    let mut explanation = format!("{}{} => {}\n", prefix, line, value);
+
+    if calibration.first == calibration.last {
+        explanation.push_str(&underline(&calibration.first, "first and last"));
+    } else {
+        explanation.push_str(&underline(&calibration.first, "first"));
+        explanation.push_str(&underline(&calibration.last, "last"));
+    }
+
+    explanation
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day1, DigitMatcher, DigitSource, ENGLISH, NUMERALS};
+    use crate::error::Error;
+    use crate::solver::Solver;
+    use crate::types::Part;
+    use crate::utils::test::{assert_answer, example_answer, solve_example};
+
+    #[test]
+    fn reports_line_without_digits() {
+        match Day1.parse("1abc2\npqrstuvwxyz") {
+            Err(Error::Parse { line, text, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(text, "pqrstuvwxyz");
+            }
+            _ => panic!("Expected parse error"),
+        }
+
+        let lines = Day1.parse("1abc2\npqrsevenz").unwrap();
+
+        match Day1.part_one(&lines) {
+            Err(Error::Parse { line, text, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(text, "pqrsevenz");
+            }
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    fn first_and_last(matcher: &DigitMatcher, text: &str) -> Option<(u32, u32)> {
+        matcher
+            .calibrate(text)
+            .map(|calibration| (calibration.first.value, calibration.last.value))
+    }
+
+    #[test]
+    fn matches_overlapping_words() {
+        let matcher = DigitMatcher::new(NUMERALS.iter().chain(&ENGLISH), false);
+
+        assert_eq!(first_and_last(&matcher, "twone"), Some((2, 1)));
+        assert_eq!(first_and_last(&matcher, "3eightwo"), Some((3, 2)));
+        assert_eq!(first_and_last(&matcher, "xoneightx"), Some((1, 8)));
+        assert_eq!(first_and_last(&matcher, "seven"), Some((7, 7)));
+        assert_eq!(first_and_last(&matcher, "TWONE"), None);
+    }
+
+    #[test]
+    fn matches_configured_vocabulary() {
+        let vocabulary = [("zero", 0), ("ten", 10), ("un", 1), ("deux", 2)];
+        let matcher = DigitMatcher::new(&vocabulary, true);
+
+        assert_eq!(first_and_last(&matcher, "Deux-zero-un"), Some((2, 1)));
+        assert_eq!(first_and_last(&matcher, "TEN"), Some((10, 10)));
+        assert_eq!(first_and_last(&matcher, "1two"), None);
+    }
+
+    #[test]
+    fn reports_digit_spans_and_sources() {
+        let matcher = DigitMatcher::new(NUMERALS.iter().chain(&ENGLISH), false);
+        let calibration = matcher.calibrate("eightwo3").unwrap();
+
+        assert_eq!(calibration.first.span, 0..5);
+        assert_eq!(calibration.first.source, DigitSource::Word);
+        assert_eq!(calibration.last.span, 7..8);
+        assert_eq!(calibration.last.source, DigitSource::Numeral);
+        assert_eq!(calibration.value(), Some(83));
+    }
+
+    #[test]
+    fn explains_lines() {
+        let lines = Day1.parse("xtwone3four\nseven").unwrap();
+
+        assert_eq!(
+            Day1.explain(&lines, Part::Two).unwrap(),
+            "1: xtwone3four => 24\n    \
+             ^^^         first 2 (word, bytes 1..4)\n          \
+             ^^^^  last 4 (word, bytes 7..11)\n\
+             2: seven => 77\n   \
+             ^^^^^  first and last 7 (word, bytes 0..5)\n"
+        );
+        assert!(Day1
+            .explain(&lines, Part::One)
+            .unwrap()
+            .ends_with("2: seven => no digits\n"));
+    }
+
+    #[test]
+    fn solve_example_part_one() {
+        assert_eq!(
+            solve_example(&Day1, Part::One),
+            example_answer(&Day1, Part::One)
+        );
+    }
+
+    #[test]
+    fn solve_example_part_two() {
+        assert_eq!(
+            solve_example(&Day1, Part::Two),
+            example_answer(&Day1, Part::Two)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_1", ignore = "input missing")]
+    fn solve_part_one() {
+        assert_answer(&Day1, Part::One);
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_1", ignore = "input missing")]
+    fn solve_part_two() {
+        assert_answer(&Day1, Part::Two);
+    }
+}
@@ -0,0 +1,637 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::vec::Vec;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input::RawInput;
+use crate::solver::{Params, Solver};
+use crate::types::Part;
+
+/// Cubes in the bag by default, by colour.
+const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// Cubes revealed at once, by colour.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Set {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Set {
+    /// Parses a set of the grammar `<n> <colour>, <n> <colour>, ...`, e.g., a bag.
+    pub fn parse(text: &str) -> Result<Set> {
+        let mut cursor = Cursor {
+            i_line: 0,
+            line: text,
+            pos: 0,
+        };
+
+        let set = cursor.set()?;
+        cursor.end()?;
+
+        Ok(set)
+    }
+
+    /// Number of cubes of a colour, zero if none were revealed.
+    pub fn get(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Colours revealed, in alphabetical order.
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.cubes.keys().map(String::as_str)
+    }
+
+    /// Number of cubes by colour, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes.iter().map(|(colour, n)| (colour.as_str(), *n))
+    }
+
+    /// Product of the cubes of each colour of `bag` and the set, zero if the set has none
+    /// of a colour of the bag, `None` on overflow.
+    pub fn power(&self, bag: &Set) -> Option<u64> {
+        let colours: BTreeSet<&str> = bag.colours().chain(self.colours()).collect();
+
+        colours.into_iter().try_fold(1u64, |power, colour| {
+            power.checked_mul(self.get(colour) as u64)
+        })
+    }
+
+    /// Most cubes of each colour of either set.
+    pub fn max(mut self, other: &Set) -> Set {
+        for (colour, n) in other.iter() {
+            let max = self.cubes.entry(colour.to_string()).or_insert(0);
+            *max = (*max).max(n);
+        }
+
+        self
+    }
+}
+
+impl<'a> FromIterator<(&'a str, u32)> for Set {
+    fn from_iter<I: IntoIterator<Item = (&'a str, u32)>>(iter: I) -> Set {
+        Set {
+            cubes: iter
+                .into_iter()
+                .map(|(colour, n)| (colour.to_string(), n))
+                .collect(),
+        }
+    }
+}
+
+impl std::fmt::Display for Set {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let cubes: Vec<String> = self
+            .iter()
+            .map(|(colour, n)| format!("{} {}", n, colour))
+            .collect();
+
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+/// Cubes of a colour revealed in a set of a game, more than in the bag.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Draw {
+    pub i_set: usize,
+    pub colour: String,
+    pub n: u32,
+    pub n_in_bag: u32,
+}
+
+/// Game as parsed, i.e., its id and the sets of cubes revealed.
+#[derive(Clone, Debug)]
+pub struct Game {
+    id: u32,
+    sets: Vec<Set>,
+}
+
+impl Game {
+    /// Parses a line of the grammar `Game <id>: <set>; <set>; ...`, where a set is
+    /// `<n> <colour>, <n> <colour>, ...` and a colour is any lowercase word.
+    pub fn from_line(i_line: usize, line: &str) -> Result<Game> {
+        log::debug!("Parsing: {}", line);
+
+        let mut cursor = Cursor {
+            i_line,
+            line,
+            pos: 0,
+        };
+
+        cursor.expect("Game")?;
+        let id = cursor.number()?;
+        cursor.expect(":")?;
+
+        log::debug!("Parsed game id: {}", id);
+
+        let mut sets: Vec<Set> = vec![cursor.set()?];
+
+        while cursor.accept(";") {
+            sets.push(cursor.set()?);
+        }
+
+        cursor.end()?;
+
+        Ok(Game { id, sets })
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    pub fn sets(&self) -> &[Set] {
+        &self.sets
+    }
+
+    /// First draw of more cubes of a colour than in `bag`, if any.
+    pub fn impossible_draw(&self, bag: &Set) -> Option<Draw> {
+        self.sets.iter().enumerate().find_map(|(i_set, set)| {
+            set.iter()
+                .find(|(colour, n)| *n > bag.get(colour))
+                .map(|(colour, n)| Draw {
+                    i_set,
+                    colour: colour.to_string(),
+                    n,
+                    n_in_bag: bag.get(colour),
+                })
+        })
+    }
+
+    pub fn is_possible(&self, bag: &Set) -> bool {
+        self.impossible_draw(bag).is_none()
+    }
+
+    /// Fewest cubes of each colour with which the game is possible.
+    pub fn minimum_set(&self) -> Set {
+        self.sets
+            .iter()
+            .fold(Set::default(), |minimum, set| minimum.max(set))
+    }
+
+    /// Product of the fewest cubes of each colour of `bag` and the game, zero if the game
+    /// reveals none of a colour of the bag.
+    pub fn power(&self, bag: &Set) -> Result<u64> {
+        self.minimum_set()
+            .power(bag)
+            .ok_or(Error::Overflow(format!("power of game {}", self.id)))
+    }
+
+    /// Cubes revealed over all sets, by colour.
+    pub fn totals(&self) -> Result<Set> {
+        let mut totals = Set::default();
+
+        for (colour, n) in self.sets.iter().flat_map(Set::iter) {
+            let total = totals.cubes.entry(colour.to_string()).or_insert(0);
+
+            *total = total.checked_add(n).ok_or(Error::Overflow(format!(
+                "total {} cubes of game {}",
+                colour, self.id
+            )))?;
+        }
+
+        Ok(totals)
+    }
+}
+
+/// Games of each line, parsed one at a time.
+pub fn games(lines: impl Iterator<Item = Result<String>>) -> impl Iterator<Item = Result<Game>> {
+    lines
+        .enumerate()
+        .map(|(i_line, line)| Game::from_line(i_line, &line?))
+}
+
+/// Games that are possible with the cubes in `bag`, passing on errors.
+pub fn possible_games<'a>(
+    games: impl Iterator<Item = Result<Game>> + 'a,
+    bag: &'a Set,
+) -> impl Iterator<Item = Result<Game>> + 'a {
+    games.filter(move |game| game.as_ref().map_or(true, |game| game.is_possible(bag)))
+}
+
+/// Totals of the games that the parts need, folded over the games one at a time, so their
+/// size does not grow with the input. The input is kept, without being read, to explain
+/// the games.
+pub struct Totals {
+    /// Sum of the ids of the games that are possible with the bag folded against.
+    pub sum_of_possible_ids: u64,
+    /// Sum of the powers of the games, see [`Game::power`].
+    pub sum_of_powers: u64,
+    /// Smallest bag with which every game is possible.
+    pub minimum_bag: Set,
+    input: RawInput,
+}
+
+impl Totals {
+    /// Folds the games of each line of the input against `bag`.
+    pub fn parse(input: &RawInput, bag: &Set) -> Result<Totals> {
+        let totals = Totals {
+            sum_of_possible_ids: 0,
+            sum_of_powers: 0,
+            minimum_bag: Set::default(),
+            input: input.clone(),
+        };
+
+        games(input.lines()?).try_fold(totals, |totals, game| {
+            let game = game?;
+
+            log::debug!("{:?}", game);
+
+            totals.add(&game, bag)
+        })
+    }
+
+    /// Adds a game, an error on overflow of a sum.
+    pub fn add(mut self, game: &Game, bag: &Set) -> Result<Totals> {
+        if game.is_possible(bag) {
+            self.sum_of_possible_ids = self
+                .sum_of_possible_ids
+                .checked_add(game.id as u64)
+                .ok_or(Error::Overflow("sum of possible game ids".to_string()))?;
+        }
+
+        self.sum_of_powers = self
+            .sum_of_powers
+            .checked_add(game.power(bag)?)
+            .ok_or(Error::Overflow("sum of powers".to_string()))?;
+        self.minimum_bag = self.minimum_bag.max(&game.minimum_set());
+
+        Ok(self)
+    }
+}
+
+/// Position in a line being parsed, skipping whitespace between tokens.
+struct Cursor<'a> {
+    i_line: usize,
+    line: &'a str,
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.line[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, message: &str) -> Error {
+        let token = self.line[self.pos..]
+            .split_whitespace()
+            .next()
+            .unwrap_or("");
+
+        Error::parse(self.i_line, self.pos, token, message)
+    }
+
+    fn accept(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        match self.line[self.pos..].starts_with(token) {
+            true => {
+                self.pos += token.len();
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<()> {
+        match self.accept(token) {
+            true => Ok(()),
+            false => Err(self.error(&format!("Expected \"{}\"", token))),
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        self.skip_whitespace();
+
+        let line = self.line;
+        let rest = &line[self.pos..];
+        let len = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.pos += len;
+
+        &rest[..len]
+    }
+
+    fn number(&mut self) -> Result<u32> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let digits = self.take_while(|c| c.is_ascii_digit());
+
+        match digits {
+            "" => Err(self.error("Expected number")),
+            digits => digits
+                .parse::<u32>()
+                .map_err(|_| Error::parse(self.i_line, start, digits, "Invalid number")),
+        }
+    }
+
+    fn colour(&mut self) -> Result<&'a str> {
+        match self.take_while(|c| c.is_ascii_lowercase()) {
+            "" => Err(self.error("Expected colour")),
+            colour => Ok(colour),
+        }
+    }
+
+    fn set(&mut self) -> Result<Set> {
+        let mut set = Set::default();
+
+        loop {
+            let n = self.number()?;
+            self.skip_whitespace();
+            let start = self.pos;
+            let colour = self.colour()?.to_string();
+
+            if set.cubes.contains_key(&colour) {
+                return Err(Error::parse(
+                    self.i_line,
+                    start,
+                    &colour,
+                    "Duplicate colour in set",
+                ));
+            }
+
+            set.cubes.insert(colour, n);
+
+            if !self.accept(",") {
+                return Ok(set);
+            }
+        }
+    }
+
+    fn end(&mut self) -> Result<()> {
+        self.skip_whitespace();
+
+        match self.pos == self.line.len() {
+            true => Ok(()),
+            false => Err(self.error("Expected \";\" or end of line")),
+        }
+    }
+}
+
+/// Solver of day 2, with the cubes in the bag configurable by the `bag` parameter, e.g.,
+/// `bag = "12 red, 13 green, 14 blue"`.
+pub struct Day2 {
+    pub bag: Set,
+}
+
+impl Default for Day2 {
+    fn default() -> Day2 {
+        Day2 {
+            bag: BAG.into_iter().collect(),
+        }
+    }
+}
+
+impl Solver for Day2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Totals;
+
+    fn parse(&self, input: &str) -> Result<Totals> {
+        self.parse_input(&RawInput::from(input))
+    }
+
+    /// Folds the games in a single pass over the lines, against the bag as configured.
+    fn parse_input(&self, input: &RawInput) -> Result<Totals> {
+        Totals::parse(input, &self.bag)
+    }
+
+    fn part_one(&self, input: &Totals) -> Result<Answer> {
+        Ok(input.sum_of_possible_ids.into())
+    }
+
+    fn part_two(&self, input: &Totals) -> Result<Answer> {
+        Ok(input.sum_of_powers.into())
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<()> {
+        for (key, value) in params {
+            match key.as_str() {
+                "bag" => self.bag = Set::parse(value)?,
+                _ => {
+                    return Err(Error::Unsupported(format!(
+                        "parameter {} of 2023 day 2",
+                        key
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the games again to explain each of them.
+    fn explain(&self, input: &Totals, part: Part) -> Result<String> {
+        let mut explanation = String::new();
+
+        for game in games(input.input.lines()?) {
+            let game = game?;
+            let line = match part {
+                Part::One => match game.impossible_draw(&self.bag) {
+                    Some(draw) => format!(
+                        "Game {}: impossible, set {} draws {} {} of {}\n",
+                        game.id,
+                        draw.i_set + 1,
+                        draw.n,
+                        draw.colour,
+                        draw.n_in_bag
+                    ),
+                    None => format!("Game {}: possible\n", game.id),
+                },
+                Part::Two => format!(
+                    "Game {}: minimum bag {} => power {}\n",
+                    game.id,
+                    game.minimum_set(),
+                    game.power(&self.bag)?
+                ),
+            };
+
+            explanation.push_str(&line);
+        }
+
+        let summary = match part {
+            Part::One => format!("Bag: {}\n", self.bag),
+            Part::Two => format!("Minimum bag of all games: {}\n", input.minimum_bag),
+        };
+
+        explanation.push_str(&summary);
+
+        Ok(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{games, possible_games, Day2, Draw, Game, Set};
+    use crate::error::{Error, Result};
+    use crate::input::RawInput;
+    use crate::solver::{Params, Solver};
+    use crate::types::Part;
+    use crate::utils::test::{assert_answer, example_answer, solve_example};
+
+    fn parse_games(text: &str) -> Vec<Game> {
+        games(RawInput::from(text).lines().unwrap())
+            .collect::<Result<Vec<Game>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn reports_missing_game_id() {
+        match Day2::default().parse("Game 1: 3 blue\nGame: 2 red") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 5)),
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn parses_any_colour() {
+        let games = parse_games("Game 7: 3 blue, 4 purple; 1 red\nGame 8: 2 green");
+
+        assert_eq!(games[0].id(), 7);
+        assert_eq!(games[0].sets()[0].get("purple"), 4);
+        assert_eq!(games[0].sets()[0].get("red"), 0);
+        assert_eq!(
+            games[0].sets()[0].colours().collect::<Vec<_>>(),
+            ["blue", "purple"]
+        );
+        assert!(!games[0].is_possible(&Day2::default().bag));
+        assert!(games[1].is_possible(&Day2::default().bag));
+    }
+
+    #[test]
+    fn answers_bag_queries() {
+        let mut day = Day2::default();
+        day.configure(&Params::from([(
+            "bag".to_string(),
+            "2 red, 1 blue".to_string(),
+        )]))
+        .unwrap();
+
+        let text = "Game 1: 1 red; 1 blue\nGame 2: 1 red, 2 blue; 3 red\nGame 3: 1 green";
+        let input = day.parse(text).unwrap();
+        let games = parse_games(text);
+
+        let possible: Vec<u32> = possible_games(games.iter().cloned().map(Ok), &day.bag)
+            .map(|game| game.map(|game| game.id()))
+            .collect::<Result<Vec<u32>>>()
+            .unwrap();
+        assert_eq!(possible, [1]);
+
+        assert_eq!(
+            games[1].impossible_draw(&day.bag),
+            Some(Draw {
+                i_set: 0,
+                colour: "blue".to_string(),
+                n: 2,
+                n_in_bag: 1
+            })
+        );
+        assert_eq!(input.minimum_bag.to_string(), "2 blue, 1 green, 3 red");
+        assert_eq!(day.part_one(&input).unwrap(), 1.into());
+    }
+
+    #[test]
+    fn explains_games() {
+        let day = Day2::default();
+        let input = day
+            .parse("Game 1: 3 blue; 20 red\nGame 2: 2 green")
+            .unwrap();
+
+        assert_eq!(
+            day.explain(&input, Part::One).unwrap(),
+            "Game 1: impossible, set 2 draws 20 red of 12\n\
+             Game 2: possible\n\
+             Bag: 14 blue, 13 green, 12 red\n"
+        );
+        assert!(day
+            .explain(&input, Part::Two)
+            .unwrap()
+            .ends_with("Minimum bag of all games: 3 blue, 2 green, 20 red\n"));
+    }
+
+    #[test]
+    fn computes_per_game_sets() {
+        let bag = Set::parse("12 red, 13 green, 14 blue").unwrap();
+        let game = Game::from_line(
+            0,
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green",
+        )
+        .unwrap();
+
+        assert_eq!(
+            game.minimum_set(),
+            Set::parse("6 blue, 13 green, 20 red").unwrap()
+        );
+        assert_eq!(game.power(&bag).unwrap(), 1560);
+        assert_eq!(
+            game.totals().unwrap(),
+            Set::parse("11 blue, 21 green, 24 red").unwrap()
+        );
+
+        let no_blue = Game::from_line(0, "Game 4: 2 red, 1 green").unwrap();
+
+        assert_eq!(no_blue.power(&bag).unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_unknown_param() {
+        let params = Params::from([("bags".to_string(), "1 red".to_string())]);
+
+        assert!(matches!(
+            Day2::default().configure(&params),
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn reports_invalid_grammar() {
+        let cases = [
+            ("Game 1 3 blue", 8, "Expected \":\""),
+            ("Game 1: 3 blue,", 16, "Expected number"),
+            ("Game 1: 3 blue; 4", 18, "Expected colour"),
+            ("Game 1: 3 blue 4 red", 16, "Expected \";\" or end of line"),
+            ("Game 1: 3 blue, 4 blue", 19, "Duplicate colour in set"),
+            ("Game 99999999999: 3 blue", 6, "Invalid number"),
+        ];
+
+        for (line, expected_column, expected_message) in cases {
+            match Day2::default().parse(line) {
+                Err(Error::Parse {
+                    column, message, ..
+                }) => assert_eq!(
+                    (column, message.as_str()),
+                    (expected_column, expected_message)
+                ),
+                _ => panic!("Expected parse error for {}", line),
+            }
+        }
+    }
+
+    #[test]
+    fn solve_example_part_one() {
+        assert_eq!(
+            solve_example(&Day2::default(), Part::One),
+            example_answer(&Day2::default(), Part::One)
+        );
+    }
+
+    #[test]
+    fn solve_example_part_two() {
+        assert_eq!(
+            solve_example(&Day2::default(), Part::Two),
+            example_answer(&Day2::default(), Part::Two)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_2", ignore = "input missing")]
+    fn solve_part_one() {
+        assert_answer(&Day2::default(), Part::One);
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_2", ignore = "input missing")]
+    fn solve_part_two() {
+        assert_answer(&Day2::default(), Part::Two);
+    }
+}
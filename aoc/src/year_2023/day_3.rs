@@ -0,0 +1,514 @@
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::grid::{Grid, Pos};
+use crate::solver::{Params, Solver};
+use crate::utils::checked_sum;
+
+/// Which characters, besides digits, are symbols.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Symbols {
+    /// Every character but these, which are empty cells.
+    AnyExcept(String),
+    /// Only these characters, any other is an empty cell.
+    Only(String),
+}
+
+impl Symbols {
+    fn contains(&self, c: char) -> bool {
+        match self {
+            Symbols::AnyExcept(chars) => !chars.contains(c),
+            Symbols::Only(chars) => chars.contains(c),
+        }
+    }
+}
+
+/// How the numbers adjacent to a gear combine into its ratio.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Combine {
+    Product,
+    Sum,
+    Max,
+}
+
+impl std::str::FromStr for Combine {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "product" => Ok(Combine::Product),
+            "sum" => Ok(Combine::Sum),
+            "max" => Ok(Combine::Max),
+            _ => Err(format!("Unsupported combine: {}", s)),
+        }
+    }
+}
+
+impl Combine {
+    fn apply(&self, values: &[u64]) -> Option<u64> {
+        match self {
+            Combine::Product => values.iter().try_fold(1_u64, |acc, v| acc.checked_mul(*v)),
+            Combine::Sum => values.iter().try_fold(0_u64, |acc, v| acc.checked_add(*v)),
+            Combine::Max => Some(values.iter().copied().max().unwrap_or(0)),
+        }
+    }
+}
+
+/// Rules of what symbols and gears are, by default those of the puzzle: any character
+/// but `.` is a symbol and a `*` adjacent to exactly two numbers is a gear, with the
+/// product of the numbers as ratio.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Rules {
+    pub symbols: Symbols,
+    pub gears: String,
+    pub n_gear_numbers: usize,
+    pub combine: Combine,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            symbols: Symbols::AnyExcept(".".to_string()),
+            gears: "*".to_string(),
+            n_gear_numbers: 2,
+            combine: Combine::Product,
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Cell {
+    Empty,
+    Digit(u8),
+    Symbol(char),
+}
+
+impl Cell {
+    fn from_char(c: char, symbols: &Symbols) -> Cell {
+        match c {
+            '0'..='9' => Cell::Digit(c as u8 - b'0'),
+            _ if symbols.contains(c) => Cell::Symbol(c),
+            _ => Cell::Empty,
+        }
+    }
+}
+
+/// Number spanning columns `start_col` to `end_col` (inclusive) of a row.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Number {
+    pub row: usize,
+    pub start_col: usize,
+    pub end_col: usize,
+    pub value: u64,
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Symbol {
+    pub pos: Pos,
+    pub symbol: char,
+}
+
+/// Engine schematic, with the numbers and symbols found in it and which of them are
+/// adjacent, including diagonally. Numbers and symbols are referred to by index.
+pub struct Schematic {
+    rules: Rules,
+    cells: Grid<Cell>,
+    numbers: Vec<Number>,
+    symbols: Vec<Symbol>,
+    number_at: Grid<Option<usize>>,
+
+    numbers_of_symbol: Vec<Vec<usize>>,
+    symbols_of_number: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    pub fn parse(text: &str) -> Result<Schematic> {
+        Schematic::parse_with_rules(text, Rules::default())
+    }
+
+    pub fn parse_with_rules(text: &str, rules: Rules) -> Result<Schematic> {
+        let cells = Grid::parse(text, |c| Some(Cell::from_char(c, &rules.symbols)))?;
+        let numbers = find_numbers(&cells)?;
+
+        let mut number_at = Grid::new(cells.n_rows(), cells.n_cols(), None);
+
+        for (i_number, number) in numbers.iter().enumerate() {
+            for col in number.start_col..=number.end_col {
+                number_at[(number.row, col)] = Some(i_number);
+            }
+        }
+
+        let symbols: Vec<Symbol> = cells
+            .iter()
+            .filter_map(|(pos, cell)| match cell {
+                Cell::Symbol(symbol) => Some(Symbol {
+                    pos,
+                    symbol: *symbol,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        let mut numbers_of_symbol: Vec<Vec<usize>> = Vec::new();
+        let mut symbols_of_number: Vec<Vec<usize>> = vec![Vec::new(); numbers.len()];
+
+        for (i_symbol, symbol) in symbols.iter().enumerate() {
+            let mut adjacent: Vec<usize> = cells
+                .neighbours_8(symbol.pos)
+                .filter_map(|pos| number_at[pos])
+                .collect();
+
+            adjacent.sort();
+            adjacent.dedup();
+
+            for i_number in &adjacent {
+                symbols_of_number[*i_number].push(i_symbol);
+            }
+
+            numbers_of_symbol.push(adjacent);
+        }
+
+        Ok(Schematic {
+            rules,
+            cells,
+            numbers,
+            symbols,
+            number_at,
+
+            numbers_of_symbol,
+            symbols_of_number,
+        })
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// Numbers in reading order.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// Symbols in reading order.
+    pub fn symbols(&self) -> &[Symbol] {
+        &self.symbols
+    }
+
+    /// Index of the number covering `pos`, if any.
+    pub fn number_at(&self, pos: Pos) -> Option<usize> {
+        self.number_at.get(pos).copied().flatten()
+    }
+
+    /// Indices of the numbers adjacent to a symbol.
+    pub fn numbers_touching(&self, i_symbol: usize) -> &[usize] {
+        &self.numbers_of_symbol[i_symbol]
+    }
+
+    /// Indices of the symbols adjacent to a number.
+    pub fn symbols_touching(&self, i_number: usize) -> &[usize] {
+        &self.symbols_of_number[i_number]
+    }
+
+    /// Numbers adjacent to at least one symbol.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .enumerate()
+            .filter(|(i_number, _)| !self.symbols_touching(*i_number).is_empty())
+            .map(|(_, number)| number)
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    /// Gear symbols with the required count of adjacent numbers, with those numbers.
+    pub fn gears(&self) -> impl Iterator<Item = (&Symbol, Vec<&Number>)> {
+        self.symbols
+            .iter()
+            .zip(&self.numbers_of_symbol)
+            .filter(|(symbol, adjacent)| {
+                self.rules.gears.contains(symbol.symbol)
+                    && adjacent.len() == self.rules.n_gear_numbers
+            })
+            .map(|(symbol, adjacent)| {
+                let numbers = adjacent.iter().map(|i| &self.numbers[*i]).collect();
+
+                (symbol, numbers)
+            })
+    }
+
+    pub fn get_sum_of_part_numbers(&self) -> Result<u64> {
+        checked_sum(
+            self.part_numbers().map(|number| number.value),
+            "sum of part numbers",
+        )
+    }
+
+    /// Ratios of the gears, their adjacent numbers combined by the rules.
+    pub fn gear_ratios(&self) -> Result<Vec<u64>> {
+        self.gears()
+            .map(|(symbol, numbers)| {
+                let values: Vec<u64> = numbers.iter().map(|number| number.value).collect();
+
+                log::debug!("Found gear {:?}: {:?}", symbol, values);
+
+                self.rules
+                    .combine
+                    .apply(&values)
+                    .ok_or(Error::Overflow(format!("gear ratio of {:?}", values)))
+            })
+            .collect()
+    }
+
+    pub fn get_sum_of_gear_ratios(&self) -> Result<u64> {
+        checked_sum(self.gear_ratios()?, "sum of gear ratios")
+    }
+}
+
+/// Runs of digits in each row, in reading order.
+fn find_numbers(cells: &Grid<Cell>) -> Result<Vec<Number>> {
+    let mut numbers: Vec<Number> = Vec::new();
+
+    for (i_row, row) in cells.rows().enumerate() {
+        let mut number: Option<Number> = None;
+
+        for (i_col, cell) in row.iter().enumerate() {
+            number = match (cell, number) {
+                (Cell::Digit(digit), None) => Some(Number {
+                    row: i_row,
+                    start_col: i_col,
+                    end_col: i_col,
+                    value: *digit as u64,
+                }),
+                (Cell::Digit(digit), Some(number)) => {
+                    let value = number
+                        .value
+                        .checked_mul(10)
+                        .and_then(|value| value.checked_add(*digit as u64));
+
+                    match value {
+                        Some(value) => Some(Number {
+                            end_col: i_col,
+                            value,
+                            ..number
+                        }),
+                        None => {
+                            let digits: String = row[number.start_col..]
+                                .iter()
+                                .map_while(|cell| match cell {
+                                    Cell::Digit(digit) => Some(char::from(b'0' + digit)),
+                                    _ => None,
+                                })
+                                .collect();
+
+                            return Err(Error::parse(
+                                i_row,
+                                number.start_col,
+                                &digits,
+                                "Invalid number",
+                            ));
+                        }
+                    }
+                }
+                (_, Some(number)) => {
+                    numbers.push(number);
+                    None
+                }
+                (_, None) => None,
+            };
+        }
+
+        numbers.extend(number);
+    }
+
+    Ok(numbers)
+}
+
+/// Solver of day 3, with the rules configurable by the parameters `symbols` (the only
+/// symbols) or `empty` (the only characters that are not symbols), `gears`,
+/// `gear_numbers` and `combine` (`product`, `sum` or `max`), e.g., `gears = "*#"`.
+#[derive(Default)]
+pub struct Day3 {
+    pub rules: Rules,
+}
+
+impl Solver for Day3 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Schematic> {
+        Schematic::parse_with_rules(input, self.rules.clone())
+    }
+
+    fn part_one(&self, input: &Schematic) -> Result<Answer> {
+        Ok(input.get_sum_of_part_numbers()?.into())
+    }
+
+    fn part_two(&self, input: &Schematic) -> Result<Answer> {
+        Ok(input.get_sum_of_gear_ratios()?.into())
+    }
+
+    fn configure(&mut self, params: &Params) -> Result<()> {
+        if params.contains_key("symbols") && params.contains_key("empty") {
+            return Err(Error::Unsupported(
+                "parameters symbols and empty of 2023 day 3 together".to_string(),
+            ));
+        }
+
+        for (key, value) in params {
+            let invalid = |message: &str| Error::parse(0, 0, value, message);
+
+            match key.as_str() {
+                "symbols" => self.rules.symbols = Symbols::Only(value.clone()),
+                "empty" => self.rules.symbols = Symbols::AnyExcept(value.clone()),
+                "gears" => self.rules.gears = value.clone(),
+                "gear_numbers" => {
+                    self.rules.n_gear_numbers = value
+                        .trim()
+                        .parse()
+                        .map_err(|_| invalid("Invalid count of gear numbers"))?
+                }
+                "combine" => {
+                    self.rules.combine = value.trim().parse().map_err(|e: String| invalid(&e))?
+                }
+                _ => {
+                    return Err(Error::Unsupported(format!(
+                        "parameter {} of 2023 day 3",
+                        key
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Combine, Day3, Number, Rules, Schematic, Symbols};
+    use crate::error::Error;
+    use crate::solver::{Params, Solver};
+    use crate::types::Part;
+    use crate::utils::test::{assert_answer, example_answer, solve_example};
+
+    #[test]
+    fn reports_ragged_rows() {
+        match Day3::default().parse("467..\n...*.\n..35") {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (3, 5)),
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn reports_invalid_number() {
+        match Day3::default().parse("..123456789012345678901234567890*") {
+            Err(Error::Parse { column, text, .. }) => {
+                assert_eq!((column, text.len()), (3, 30));
+            }
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn adjacency_of_numbers_and_symbols() {
+        let schematic = Schematic::parse("467..114\n...*....\n..35.#..").unwrap();
+
+        assert_eq!(
+            schematic.numbers()[2],
+            Number {
+                row: 2,
+                start_col: 2,
+                end_col: 3,
+                value: 35
+            }
+        );
+        assert_eq!(schematic.number_at((0, 6)), Some(1));
+        assert_eq!(schematic.numbers_touching(0), [0, 2]);
+        assert_eq!(schematic.numbers_touching(1), [] as [usize; 0]);
+        assert_eq!(schematic.symbols_touching(2), [0]);
+        assert_eq!(schematic.part_numbers().count(), 2);
+        assert_eq!(schematic.gears().count(), 1);
+    }
+
+    #[test]
+    fn gears_by_custom_rules() {
+        let rules = Rules {
+            symbols: Symbols::Only("#*".to_string()),
+            gears: "#".to_string(),
+            n_gear_numbers: 1,
+            combine: Combine::Sum,
+        };
+        let schematic = Schematic::parse_with_rules("467.$114\n...*..#.\n..35....", rules).unwrap();
+
+        assert_eq!(schematic.symbols().len(), 2);
+        assert_eq!(schematic.gear_ratios().unwrap(), vec![114]);
+        assert_eq!(schematic.get_sum_of_part_numbers().unwrap(), 467 + 114 + 35);
+    }
+
+    #[test]
+    fn configures_rules() {
+        let mut day = Day3::default();
+        let params = |pairs: &[(&str, &str)]| -> Params {
+            pairs
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect()
+        };
+
+        day.configure(&params(&[
+            ("symbols", "#*"),
+            ("gears", "#"),
+            ("gear_numbers", "1"),
+            ("combine", "sum"),
+        ]))
+        .unwrap();
+
+        let schematic = day.parse("467.$114\n...*..#.\n..35....").unwrap();
+
+        assert_eq!(day.part_two(&schematic).unwrap(), 114.into());
+        assert!(matches!(
+            day.configure(&params(&[("combine", "min")])),
+            Err(Error::Parse { .. })
+        ));
+        assert!(matches!(
+            day.configure(&params(&[("symbols", "#"), ("empty", ".")])),
+            Err(Error::Unsupported(_))
+        ));
+        assert!(matches!(
+            day.configure(&params(&[("gear", "#")])),
+            Err(Error::Unsupported(_))
+        ));
+    }
+
+    #[test]
+    fn solve_example_part_one() {
+        assert_eq!(
+            solve_example(&Day3::default(), Part::One),
+            example_answer(&Day3::default(), Part::One)
+        );
+    }
+
+    #[test]
+    fn solve_example_part_two() {
+        assert_eq!(
+            solve_example(&Day3::default(), Part::Two),
+            example_answer(&Day3::default(), Part::Two)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_3", ignore = "input missing")]
+    fn solve_part_one() {
+        assert_answer(&Day3::default(), Part::One);
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_3", ignore = "input missing")]
+    fn solve_part_two() {
+        assert_answer(&Day3::default(), Part::Two);
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, HashSet};
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input::RawInput;
+use crate::solver::Solver;
+use crate::utils::checked_sum;
+
+/// Scratchcard with its winning numbers and the numbers dealt.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub dealt: Vec<u32>,
+}
+
+impl Card {
+    /// Count of dealt numbers that are winning numbers.
+    pub fn matches(&self) -> u32 {
+        let winning: HashSet<u32> = self.winning.iter().copied().collect();
+
+        self.dealt
+            .iter()
+            .filter(|number| winning.contains(number))
+            .count() as u32
+    }
+
+    /// Points of the card, one for the first match and doubled for every other.
+    pub fn points(&self) -> Result<u64> {
+        points(self.id, self.matches())
+    }
+}
+
+/// Cards by id, summarised by their count of matches, in any order of id but all with the
+/// same count of winning and dealt numbers.
+#[derive(PartialEq, Eq, Clone, Debug, Default)]
+pub struct Cards {
+    matches: BTreeMap<u32, u32>,
+}
+
+impl Cards {
+    pub fn parse(text: &str) -> Result<Cards> {
+        Cards::from_lines(text.lines().map(|line| Ok(line.to_string())))
+    }
+
+    /// Parses the cards one line at a time, holding only their matches.
+    pub fn from_lines(lines: impl Iterator<Item = Result<String>>) -> Result<Cards> {
+        let mut cards = Cards::default();
+        let mut shape: Option<(usize, usize)> = None;
+
+        for (i_line, line) in lines.enumerate() {
+            let line = line?;
+            let card = parse_card(i_line, &line)?;
+
+            check_shape(&mut shape, &card, i_line, &line)?;
+
+            if cards.matches.contains_key(&card.id) {
+                return Err(Error::parse(i_line, 0, &line, "Duplicate card id"));
+            }
+
+            cards.matches.insert(card.id, card.matches());
+        }
+
+        Ok(cards)
+    }
+
+    /// Count of matches of a card.
+    pub fn matches(&self, id: u32) -> Option<u32> {
+        self.matches.get(&id).copied()
+    }
+
+    /// Ids and counts of matches of the cards, in order of id.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        self.matches.iter().map(|(id, matches)| (*id, *matches))
+    }
+
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// Points of each card, see [`Card::points`].
+    pub fn points(&self) -> impl Iterator<Item = Result<u64>> + '_ {
+        self.iter().map(|(id, matches)| points(id, matches))
+    }
+
+    /// Count of each card by id, the original and the copies won, where `n` matches
+    /// of a card win a copy of each of the `n` cards with the following ids, for
+    /// every copy of the card.
+    pub fn copies(&self) -> Result<BTreeMap<u32, u64>> {
+        let mut copies: BTreeMap<u32, u64> = self.matches.keys().map(|id| (*id, 1)).collect();
+
+        for (id, matches) in self.iter() {
+            let n_copies = copies[&id];
+
+            for following in following_ids(id, matches) {
+                if let Some(count) = copies.get_mut(&following) {
+                    *count = count
+                        .checked_add(n_copies)
+                        .ok_or(Error::Overflow(format!("copies of card {}", following)))?;
+                }
+            }
+        }
+
+        Ok(copies)
+    }
+}
+
+/// Points of a card with `matches`, one for the first match and doubled for every other.
+fn points(id: u32, matches: u32) -> Result<u64> {
+    match matches {
+        0 => Ok(0),
+        n_matches => 2_u64
+            .checked_pow(n_matches - 1)
+            .ok_or(Error::Overflow(format!("points of card {}", id))),
+    }
+}
+
+/// Ids of the cards a card wins copies of, one for each match, up to the largest id.
+fn following_ids(id: u32, matches: u32) -> impl Iterator<Item = u32> {
+    (1..=matches).map_while(move |k| id.checked_add(k))
+}
+
+/// Checks that a card has the count of winning and dealt numbers of the first one.
+fn check_shape(
+    shape: &mut Option<(usize, usize)>,
+    card: &Card,
+    i_line: usize,
+    line: &str,
+) -> Result<()> {
+    let card_shape = (card.winning.len(), card.dealt.len());
+
+    match shape {
+        None => *shape = Some(card_shape),
+        Some((n_winning, n_dealt)) if card_shape != (*n_winning, *n_dealt) => {
+            return Err(Error::parse(
+                i_line,
+                0,
+                line,
+                &format!(
+                    "Dimension mismatch, expected {} winning and {} dealt numbers",
+                    n_winning, n_dealt
+                ),
+            ));
+        }
+        Some(_) => {}
+    }
+
+    Ok(())
+}
+
+/// Parses a line of `Card <id>: <winning numbers> | <dealt numbers>`.
+fn parse_card(i_line: usize, line: &str) -> Result<Card> {
+    let malformed =
+        |i_col: usize, text: &str, message: &str| Error::parse(i_line, i_col, text, message);
+
+    let (head, numbers) = line.split_once(':').ok_or(malformed(
+        0,
+        line,
+        "Expected \"Card <id>: <numbers> | <numbers>\"",
+    ))?;
+
+    let id = head
+        .strip_prefix("Card")
+        .ok_or(malformed(0, head, "Expected \"Card <id>\""))?;
+    let id = id
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| malformed(4, id, "Invalid card id"))?;
+
+    let i_numbers = head.len() + 1;
+    let (winning, dealt) =
+        numbers
+            .split_once('|')
+            .ok_or(malformed(i_numbers, numbers, "Expected \"|\""))?;
+
+    let i_dealt = i_numbers + winning.len() + 1;
+
+    Ok(Card {
+        id,
+        winning: parse_numbers(i_line, i_numbers, winning)?,
+        dealt: parse_numbers(i_line, i_dealt, dealt)?,
+    })
+}
+
+/// Parses space separated numbers of a `text` starting at byte `i_col` of the line.
+fn parse_numbers(i_line: usize, i_col: usize, text: &str) -> Result<Vec<u32>> {
+    let mut numbers: Vec<u32> = Vec::new();
+    let mut offset = i_col;
+
+    for word in text.split(' ') {
+        if !word.is_empty() {
+            let number = word
+                .parse::<u32>()
+                .map_err(|_| Error::parse(i_line, offset, word, "Invalid number"))?;
+
+            numbers.push(number);
+        }
+
+        offset += word.len() + 1;
+    }
+
+    Ok(numbers)
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Cards;
+
+    fn parse(&self, input: &str) -> Result<Cards> {
+        Cards::parse(input)
+    }
+
+    fn parse_input(&self, input: &RawInput) -> Result<Cards> {
+        Cards::from_lines(input.lines()?)
+    }
+
+    fn part_one(&self, input: &Cards) -> Result<Answer> {
+        let points = input.points().collect::<Result<Vec<u64>>>()?;
+
+        Ok(checked_sum(points, "sum of points")?.into())
+    }
+
+    fn part_two(&self, input: &Cards) -> Result<Answer> {
+        Ok(checked_sum(input.copies()?.into_values(), "sum of copies")?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_card, Card, Cards, Day4};
+    use crate::error::Error;
+    use crate::input::RawInput;
+    use crate::solver::Solver;
+    use crate::types::Part;
+    use crate::utils::test::{
+        assert_answer, example_answer, get_example_path, solve_example, temp_path,
+    };
+
+    #[test]
+    fn parse_cards_by_id() {
+        let cards = Cards::parse("Card 3: 41 48 | 83 86  6\nCard  12: 13 32 | 61 30 68").unwrap();
+
+        assert_eq!(cards.len(), 2);
+        assert_eq!(cards.matches(12), Some(0));
+        assert_eq!(
+            parse_card(1, "Card  12: 13 32 | 61 30 68").unwrap(),
+            Card {
+                id: 12,
+                winning: vec![13, 32],
+                dealt: vec![61, 30, 68]
+            }
+        );
+    }
+
+    #[test]
+    fn counts_copies() {
+        let text = std::fs::read_to_string(get_example_path(2023, 4, Part::Two)).unwrap();
+        let copies = Cards::parse(&text).unwrap().copies().unwrap();
+
+        assert_eq!(
+            copies.into_iter().collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2), (3, 4), (4, 8), (5, 14), (6, 1)]
+        );
+    }
+
+    #[test]
+    fn counts_copies_of_many_cards() {
+        let n_cards: u64 = 100_000;
+        let text: String = (1..=n_cards)
+            .map(|id| format!("Card {}: 1 | 1 2\n", id))
+            .collect();
+
+        let copies = Cards::parse(&text).unwrap().copies().unwrap();
+
+        assert_eq!(copies.values().sum::<u64>(), n_cards * (n_cards + 1) / 2);
+    }
+
+    #[test]
+    fn streams_cards_from_file() {
+        let n_cards: u64 = 100_000;
+        let path = temp_path("day_4_streams_cards_from_file.txt");
+        let text: String = (1..=n_cards)
+            .map(|id| format!("Card {}: 1 | 1 2\n", id))
+            .collect();
+
+        std::fs::write(&path, text).unwrap();
+
+        let input = RawInput::File(path.clone());
+        let copies = Day4
+            .parse_input(&input)
+            .and_then(|input| Day4.part_two(&input));
+
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(copies.unwrap(), (n_cards * (n_cards + 1) / 2).into());
+    }
+
+    #[test]
+    fn counts_copies_of_largest_id() {
+        let text = "Card 4294967294: 1 2 | 1 2\nCard 4294967295: 1 2 | 1 2";
+
+        assert_eq!(
+            Cards::parse(text).unwrap().copies().unwrap(),
+            [(u32::MAX - 1, 1), (u32::MAX, 2)].into()
+        );
+        assert_eq!(Day4.part_two(&Day4.parse(text).unwrap()).unwrap(), 3.into());
+    }
+
+    #[test]
+    fn counts_copies_in_any_order() {
+        let cards = Cards::parse("Card 2: 41 | 41\nCard 1: 13 | 13\nCard 3: 7 | 8").unwrap();
+
+        assert_eq!(cards.copies().unwrap(), [(1, 1), (2, 2), (3, 3)].into());
+
+        match Cards::parse("Card 2: 41 | 41\nCard 2: 13 | 13") {
+            Err(Error::Parse { line, message, .. }) => {
+                assert_eq!((line, message.as_str()), (2, "Duplicate card id"));
+            }
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn reports_dimension_mismatch() {
+        let lines = "Card 1: 41 48 83 | 83 86  6\nCard 2: 13 32 | 61 30 68";
+
+        match Day4.parse(lines) {
+            Err(Error::Parse { line, message, .. }) => {
+                assert_eq!(line, 2);
+                assert_eq!(
+                    message,
+                    "Dimension mismatch, expected 3 winning and 3 dealt numbers"
+                );
+            }
+            _ => panic!("Expected parse error"),
+        }
+    }
+
+    #[test]
+    fn reports_malformed_line() {
+        match Day4.parse("Card 1: 41 48 83 | 83 8x  6") {
+            Err(Error::Parse {
+                column, message, ..
+            }) => {
+                assert_eq!(column, 23);
+                assert_eq!(message, "Invalid number");
+            }
+            _ => panic!("Expected parse error"),
+        }
+
+        assert!(Day4.parse("Card 1: 41 48 83 83 86  6").is_err());
+        assert!(Day4.parse("Card one: 41 | 83").is_err());
+    }
+
+    #[test]
+    fn solve_example_part_one() {
+        assert_eq!(
+            solve_example(&Day4, Part::One),
+            example_answer(&Day4, Part::One)
+        );
+    }
+
+    #[test]
+    fn solve_example_part_two() {
+        assert_eq!(
+            solve_example(&Day4, Part::Two),
+            example_answer(&Day4, Part::Two)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_4", ignore = "input missing")]
+    fn solve_part_one() {
+        assert_answer(&Day4, Part::One);
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "2023_4", ignore = "input missing")]
+    fn solve_part_two() {
+        assert_answer(&Day4, Part::Two);
+    }
+}
@@ -0,0 +1,16 @@
+//! Solvers of the 2023 puzzles.
+
+use crate::solver::Registry;
+
+pub mod day_1;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+
+/// Registers the days of the year.
+pub fn register(registry: &mut Registry) {
+    registry.register(day_1::Day1);
+    registry.register(day_2::Day2::default());
+    registry.register(day_3::Day3::default());
+    registry.register(day_4::Day4);
+}
@@ -0,0 +1,62 @@
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solver::Solver;
+
+pub struct Day{day};
+
+impl Solver for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+    const TITLE: &'static str = {title};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(input.len().into())
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(input.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day{day};
+    use crate::types::Part;
+    use crate::utils::test::{assert_answer, example_answer, solve_example};
+
+    #[test]
+    #[ignore = "answers not recorded yet"]
+    fn solve_example_part_one() {
+        assert_eq!(
+            solve_example(&Day{day}, Part::One),
+            example_answer(&Day{day}, Part::One)
+        );
+    }
+
+    #[test]
+    #[ignore = "answers not recorded yet"]
+    fn solve_example_part_two() {
+        assert_eq!(
+            solve_example(&Day{day}, Part::Two),
+            example_answer(&Day{day}, Part::Two)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "{year}_{day}", ignore = "input missing")]
+    fn solve_part_one() {
+        assert_answer(&Day{day}, Part::One);
+    }
+
+    #[test]
+    #[cfg_attr(missing_input = "{year}_{day}", ignore = "input missing")]
+    fn solve_part_two() {
+        assert_answer(&Day{day}, Part::Two);
+    }
+}
@@ -0,0 +1,10 @@
+//! Solvers of the {year} puzzles.
+
+use crate::solver::Registry;
+
+pub mod day_{day};
+
+/// Registers the days of the year.
+pub fn register(registry: &mut Registry) {
+    registry.register(day_{day}::Day{day});
+}